@@ -9,21 +9,26 @@ pub mod switchboard_feed_client {
     pub fn read_feed(ctx: Context<ReadFeed>, params: ReadFeedParams) -> Result<()> {
         let feed = &ctx.accounts.aggregator.load()?;
 
-        // get result
-        let val: f64 = feed.get_result()?.try_into()?;
-
-        // check whether the feed has been updated in the last 300 seconds
-        feed.check_staleness(
-            solana_program::clock::Clock::get().unwrap().unix_timestamp,
-            300,
-        )
-        .map_err(|_| error!(SwitchboardClientError::StaleFeed))?;
-
-        // check feed does not exceed max_confidence_interval
+        // check whether the feed has been updated in the last 300 seconds and, if provided,
+        // does not exceed max_confidence_interval
+        let mut policy = FeedPolicy::new().max_staleness(300);
         if let Some(max_confidence_interval) = params.max_confidence_interval {
-            feed.check_confidence_interval(SwitchboardDecimal::from_f64(max_confidence_interval))
-                .map_err(|_| error!(SwitchboardClientError::ConfidenceIntervalExceeded))?;
+            policy = policy
+                .max_std_deviation(SwitchboardDecimal::try_from_f64(max_confidence_interval)?);
+        }
+        let report = policy.evaluate(feed, &Clock::get()?)?;
+        if report.staleness.is_failed() {
+            return Err(error!(SwitchboardClientError::StaleFeed));
         }
+        if report.confidence_interval.is_failed() {
+            return Err(error!(SwitchboardClientError::ConfidenceIntervalExceeded));
+        }
+        if let Some(err) = report.error() {
+            return Err(err.into());
+        }
+
+        // get result
+        let val: f64 = report.result.try_into()?;
 
         msg!("Current feed result is {}!", val);
 
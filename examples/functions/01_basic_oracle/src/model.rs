@@ -49,18 +49,13 @@ pub struct OracleDataWithTradingSymbol {
 }
 
 impl OracleData {
-    pub fn get_fair_price(&self) -> anchor_lang::Result<f64> {
+    /// Returns the price as a token amount with the given number of decimals.
+    pub fn get_fair_price(&self, decimals: u32) -> anchor_lang::Result<u64> {
         // Check the price was updated in the last 10 seconds
 
         // Do some logic here based on the twap
 
-        let price: f64 = SwitchboardDecimal {
-            mantissa: self.price,
-            scale: 9,
-        }
-        .try_into()?;
-
-        Ok(price)
+        Ok(SwitchboardDecimal::from_fixed(self.price, 9).to_fixed_u64(decimals, Rounding::Floor)?)
     }
 }
 
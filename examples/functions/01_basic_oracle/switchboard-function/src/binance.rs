@@ -207,6 +207,6 @@ impl Binance {
 // Convert a string to an i128 scaled to 9 decimal places
 pub fn parse_string_value(value: &str) -> i128 {
     let f64_value = value.parse::<f64>().unwrap();
-    let sb_decimal = SwitchboardDecimal::from_f64(f64_value);
+    let sb_decimal = SwitchboardDecimal::try_from_f64(f64_value).unwrap();
     sb_decimal.scale_to(9)
 }
@@ -0,0 +1,1277 @@
+//! The decimal type Switchboard accounts store results in, and its checked math.
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{borsh, AnchorDeserialize, AnchorSerialize};
+use bytemuck::{Pod, Zeroable};
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, MathematicalOps};
+
+/// The reason a decimal operation failed. Each variant matches the `SwitchboardError` variant
+/// of the same name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecimalError {
+    /// A string is not a valid decimal.
+    InvalidStrDecimalConversion,
+    /// A decimal cannot be represented by a `rust_decimal::Decimal`, or has no square root.
+    DecimalConversionError,
+    /// A result does not fit in its type.
+    IntegerOverflowError,
+}
+
+impl DecimalError {
+    /// Returns the name of the matching `SwitchboardError` variant.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DecimalError::InvalidStrDecimalConversion => "InvalidStrDecimalConversion",
+            DecimalError::DecimalConversionError => "DecimalConversionError",
+            DecimalError::IntegerOverflowError => "IntegerOverflowError",
+        }
+    }
+
+    /// Returns the position of the matching variant in the `SwitchboardError` enum, its anchor
+    /// error code less the 6000 offset.
+    pub fn code(&self) -> u32 {
+        match self {
+            DecimalError::InvalidStrDecimalConversion => 1,
+            DecimalError::DecimalConversionError => 2,
+            DecimalError::IntegerOverflowError => 3,
+        }
+    }
+}
+
+impl fmt::Display for DecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            DecimalError::InvalidStrDecimalConversion => {
+                "Failed to convert string to decimal format"
+            }
+            DecimalError::DecimalConversionError => "Decimal conversion method failed",
+            DecimalError::IntegerOverflowError => "An integer overflow occurred",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for DecimalError {}
+
+#[cfg(feature = "anchor")]
+impl From<DecimalError> for anchor_lang::error::Error {
+    fn from(err: DecimalError) -> Self {
+        anchor_lang::error::AnchorError {
+            error_name: err.name().to_string(),
+            error_code_number: anchor_lang::error::ERROR_CODE_OFFSET + err.code(),
+            error_msg: err.to_string(),
+            error_origin: None,
+            compared_values: None,
+        }
+        .into()
+    }
+}
+
+/// The rounding strategy used when a [`SwitchboardDecimal`] drops decimal places.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero, discarding the dropped digits.
+    #[default]
+    TowardZero,
+    /// Round to the nearest value, ties away from zero.
+    HalfAwayFromZero,
+    /// Round to the nearest value, ties to the nearest even value.
+    HalfEven,
+}
+
+impl Rounding {
+    /// Divides the numerator by a positive denominator, rounding the quotient.
+    fn div(&self, numerator: i128, denominator: i128) -> Option<i128> {
+        let quotient = numerator.checked_div(denominator)?;
+        let remainder = numerator.checked_rem(denominator)?;
+        if remainder == 0 {
+            return Some(quotient);
+        }
+        let away_from_zero = quotient.checked_add(numerator.signum())?;
+        let remainder = remainder.unsigned_abs();
+        let rest = denominator.unsigned_abs() - remainder;
+        let rounded = match self {
+            Rounding::Floor if numerator < 0 => away_from_zero,
+            Rounding::Ceil if numerator > 0 => away_from_zero,
+            Rounding::HalfAwayFromZero if remainder >= rest => away_from_zero,
+            Rounding::HalfEven if remainder > rest || (remainder == rest && quotient % 2 != 0) => {
+                away_from_zero
+            }
+            _ => quotient,
+        };
+        Some(rounded)
+    }
+}
+
+#[derive(Default, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct BorshDecimal {
+    pub mantissa: i128,
+    pub scale: u32,
+}
+impl From<Decimal> for BorshDecimal {
+    fn from(s: Decimal) -> Self {
+        Self {
+            mantissa: s.mantissa(),
+            scale: s.scale(),
+        }
+    }
+}
+impl From<&Decimal> for BorshDecimal {
+    fn from(s: &Decimal) -> Self {
+        Self {
+            mantissa: s.mantissa(),
+            scale: s.scale(),
+        }
+    }
+}
+impl From<SwitchboardDecimal> for BorshDecimal {
+    fn from(s: SwitchboardDecimal) -> Self {
+        Self {
+            mantissa: s.mantissa,
+            scale: s.scale,
+        }
+    }
+}
+impl From<BorshDecimal> for SwitchboardDecimal {
+    fn from(val: BorshDecimal) -> Self {
+        SwitchboardDecimal {
+            mantissa: val.mantissa,
+            scale: val.scale,
+        }
+    }
+}
+impl BorshDecimal {
+    /// Returns the decimal at the new scale, see [`SwitchboardDecimal::rescale`].
+    pub fn rescale(&self, new_scale: u32, rounding: Rounding) -> Result<Self, DecimalError> {
+        SwitchboardDecimal::from(*self)
+            .rescale(new_scale, rounding)
+            .map(Self::from)
+    }
+
+    /// Returns the sum of two decimals.
+    pub fn checked_add(self, other: Self) -> Result<Self, DecimalError> {
+        SwitchboardDecimal::from(self)
+            .checked_add(other.into())
+            .map(Self::from)
+    }
+
+    /// Returns the difference of two decimals.
+    pub fn checked_sub(self, other: Self) -> Result<Self, DecimalError> {
+        SwitchboardDecimal::from(self)
+            .checked_sub(other.into())
+            .map(Self::from)
+    }
+
+    /// Returns the product of two decimals.
+    pub fn checked_mul(self, other: Self) -> Result<Self, DecimalError> {
+        SwitchboardDecimal::from(self)
+            .checked_mul(other.into())
+            .map(Self::from)
+    }
+
+    /// Returns the quotient of two decimals.
+    pub fn checked_div(self, other: Self) -> Result<Self, DecimalError> {
+        SwitchboardDecimal::from(self)
+            .checked_div(other.into())
+            .map(Self::from)
+    }
+
+    /// Returns the decimal raised to an integer power.
+    pub fn checked_powi(self, exp: i64) -> Result<Self, DecimalError> {
+        SwitchboardDecimal::from(self)
+            .checked_powi(exp)
+            .map(Self::from)
+    }
+
+    /// Returns the square root of the decimal.
+    pub fn checked_sqrt(self) -> Result<Self, DecimalError> {
+        SwitchboardDecimal::from(self)
+            .checked_sqrt()
+            .map(Self::from)
+    }
+
+    /// Returns the absolute value of the decimal.
+    pub fn abs(self) -> Result<Self, DecimalError> {
+        SwitchboardDecimal::from(self).abs().map(Self::from)
+    }
+
+    /// Returns the negated decimal.
+    pub fn checked_neg(self) -> Result<Self, DecimalError> {
+        SwitchboardDecimal::from(self).checked_neg().map(Self::from)
+    }
+
+    /// Returns the smaller of two decimals.
+    pub fn checked_min(self, other: Self) -> Self {
+        SwitchboardDecimal::from(self)
+            .checked_min(other.into())
+            .into()
+    }
+
+    /// Returns the larger of two decimals.
+    pub fn checked_max(self, other: Self) -> Self {
+        SwitchboardDecimal::from(self)
+            .checked_max(other.into())
+            .into()
+    }
+}
+
+impl TryInto<Decimal> for &BorshDecimal {
+    type Error = DecimalError;
+    fn try_into(self) -> Result<Decimal, DecimalError> {
+        Decimal::try_from_i128_with_scale(self.mantissa, self.scale)
+            .map_err(|_| DecimalError::DecimalConversionError)
+    }
+}
+
+impl TryInto<Decimal> for BorshDecimal {
+    type Error = DecimalError;
+    fn try_into(self) -> Result<Decimal, DecimalError> {
+        Decimal::try_from_i128_with_scale(self.mantissa, self.scale)
+            .map_err(|_| DecimalError::DecimalConversionError)
+    }
+}
+
+impl FromStr for BorshDecimal {
+    type Err = DecimalError;
+    fn from_str(s: &str) -> Result<Self, DecimalError> {
+        SwitchboardDecimal::from_str(s).map(Self::from)
+    }
+}
+
+impl fmt::Display for BorshDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        SwitchboardDecimal::from(*self).fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BorshDecimal {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BorshDecimal {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        <SwitchboardDecimal as serde::Deserialize>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[repr(C, packed)]
+#[derive(Default, Debug, Eq, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "anchor", derive(AnchorDeserialize))]
+pub struct SwitchboardDecimal {
+    /// The part of a floating-point number that represents the significant digits of that number, and that is multiplied by the base, 10, raised to the power of scale to give the actual value of the number.
+    pub mantissa: i128,
+    /// The number of decimal places to move to the left to yield the actual value.
+    pub scale: u32,
+}
+unsafe impl Pod for SwitchboardDecimal {}
+unsafe impl Zeroable for SwitchboardDecimal {}
+
+impl SwitchboardDecimal {
+    pub fn new(mantissa: i128, scale: u32) -> SwitchboardDecimal {
+        Self { mantissa, scale }
+    }
+    pub fn from_rust_decimal(d: Decimal) -> SwitchboardDecimal {
+        Self::new(d.mantissa(), d.scale())
+    }
+    /// Converts an f64, panicking on NaN, infinity or a value out of `Decimal`'s range.
+    #[deprecated(note = "use try_from_f64, which returns an error instead of panicking")]
+    pub fn from_f64(v: f64) -> SwitchboardDecimal {
+        let dec = Decimal::from_f64(v).unwrap();
+        Self::from_rust_decimal(dec)
+    }
+    /// Converts an f64. Errors on NaN, infinity or a value out of `Decimal`'s range.
+    pub fn try_from_f64(v: f64) -> Result<SwitchboardDecimal, DecimalError> {
+        Decimal::from_f64(v)
+            .map(Self::from_rust_decimal)
+            .ok_or(DecimalError::DecimalConversionError)
+    }
+    /// Returns the mantissa at the new scale, truncating any dropped digits. A mantissa that
+    /// overflows at the new scale saturates to `i128::MAX` or `i128::MIN`.
+    #[deprecated(note = "use checked_scale_to, which returns an error on overflow")]
+    pub fn scale_to(&self, new_scale: u32) -> i128 {
+        self.saturating_scale_to(new_scale)
+    }
+    /// Returns the decimal at the new scale, truncating any dropped digits. A mantissa that
+    /// overflows at the new scale saturates to `i128::MAX` or `i128::MIN`.
+    #[deprecated(note = "use rescale, which returns an error on overflow")]
+    pub fn new_with_scale(&self, new_scale: u32) -> Self {
+        SwitchboardDecimal {
+            mantissa: self.saturating_scale_to(new_scale),
+            scale: new_scale,
+        }
+    }
+
+    fn saturating_scale_to(&self, new_scale: u32) -> i128 {
+        let mantissa = self.mantissa;
+        // only upscaling a non-zero mantissa can overflow
+        self.checked_scale_to(new_scale, Rounding::TowardZero)
+            .unwrap_or(if mantissa < 0 { i128::MIN } else { i128::MAX })
+    }
+
+    /// Returns the mantissa at the new scale, rounding any dropped digits with the given strategy.
+    pub fn checked_scale_to(
+        &self,
+        new_scale: u32,
+        rounding: Rounding,
+    ) -> Result<i128, DecimalError> {
+        let mantissa = self.mantissa;
+        let scale = self.scale;
+        match scale.cmp(&new_scale) {
+            Ordering::Greater => match 10_i128.checked_pow(scale - new_scale) {
+                Some(divisor) => rounding
+                    .div(mantissa, divisor)
+                    .ok_or(DecimalError::IntegerOverflowError),
+                // the divisor exceeds any i128 mantissa so only the rounding direction remains
+                None => Ok(match rounding {
+                    Rounding::Floor if mantissa < 0 => -1,
+                    Rounding::Ceil if mantissa > 0 => 1,
+                    _ => 0,
+                }),
+            },
+            Ordering::Less if mantissa == 0 => Ok(0),
+            Ordering::Less => 10_i128
+                .checked_pow(new_scale - scale)
+                .and_then(|multiplier| mantissa.checked_mul(multiplier))
+                .ok_or(DecimalError::IntegerOverflowError),
+            Ordering::Equal => Ok(mantissa),
+        }
+    }
+
+    /// Returns the decimal at the new scale, rounding any dropped digits with the given strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_core::{Rounding, SwitchboardDecimal};
+    ///
+    /// // 1.2345 -> 1.24
+    /// let price = SwitchboardDecimal::new(12345, 4).rescale(2, Rounding::Ceil)?;
+    /// ```
+    pub fn rescale(&self, new_scale: u32, rounding: Rounding) -> Result<Self, DecimalError> {
+        Ok(Self::new(
+            self.checked_scale_to(new_scale, rounding)?,
+            new_scale,
+        ))
+    }
+
+    /// Returns the same value with trailing zeros stripped from the mantissa, e.g. 1.500 -> 1.5.
+    pub fn normalize(&self) -> Self {
+        let mut mantissa = self.mantissa;
+        let mut scale = self.scale;
+        if mantissa == 0 {
+            return Self::new(0, 0);
+        }
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Self::new(mantissa, scale)
+    }
+
+    /// Returns the decimal for a fixed-point integer with the given number of decimals, e.g. a
+    /// token amount in its smallest unit.
+    pub fn from_fixed(mantissa: i128, decimals: u32) -> Self {
+        Self::new(mantissa, decimals)
+    }
+
+    /// Returns the decimal as a fixed-point u64 with the given number of decimals.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{AggregatorAccountData, Rounding};
+    ///
+    /// let feed = AggregatorAccountData::new(feed_account_info)?;
+    /// // the price of one token in USDC base units
+    /// let price = feed.get_result()?.to_fixed_u64(6, Rounding::Floor)?;
+    /// ```
+    pub fn to_fixed_u64(&self, decimals: u32, rounding: Rounding) -> Result<u64, DecimalError> {
+        u64::try_from(self.checked_scale_to(decimals, rounding)?)
+            .map_err(|_| DecimalError::IntegerOverflowError)
+    }
+
+    /// Returns the decimal as a fixed-point u128 with the given number of decimals.
+    pub fn to_fixed_u128(&self, decimals: u32, rounding: Rounding) -> Result<u128, DecimalError> {
+        u128::try_from(self.checked_scale_to(decimals, rounding)?)
+            .map_err(|_| DecimalError::IntegerOverflowError)
+    }
+
+    /// Returns the decimal as a fixed-point i128 with the given number of decimals.
+    pub fn to_fixed_i128(&self, decimals: u32, rounding: Rounding) -> Result<i128, DecimalError> {
+        self.checked_scale_to(decimals, rounding)
+    }
+
+    /// Returns the cross rate of two prices quoted in the same currency, rounded half to even
+    /// to `out_decimals` decimal places. The quotient is computed on the i128 mantissas.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The price of the base asset, e.g. BTC/USD.
+    /// * `quote` - The price of the quote asset, e.g. ETH/USD.
+    /// * `out_decimals` - The scale of the returned price, e.g. BTC/ETH.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{AggregatorAccountData, SwitchboardDecimal};
+    ///
+    /// let btc_usd = AggregatorAccountData::new(btc_feed_account_info)?.get_result()?;
+    /// let eth_usd = AggregatorAccountData::new(eth_feed_account_info)?.get_result()?;
+    /// let btc_eth = SwitchboardDecimal::price_ratio(btc_usd, eth_usd, 9)?;
+    /// ```
+    pub fn price_ratio(base: Self, quote: Self, out_decimals: u32) -> Result<Self, DecimalError> {
+        let base_mantissa = base.mantissa;
+        let quote_mantissa = quote.mantissa;
+        if quote_mantissa == 0 {
+            return Err(DecimalError::IntegerOverflowError);
+        }
+
+        // base / quote * 10^out = base_mantissa * 10^(out + quote_scale - base_scale) / quote_mantissa
+        let exp = i64::from(out_decimals) + i64::from(quote.scale) - i64::from(base.scale);
+        let pow10 = |exp: i64| {
+            u32::try_from(exp)
+                .ok()
+                .and_then(|exp| 10_i128.checked_pow(exp))
+        };
+        let (numerator, denominator) = if exp >= 0 {
+            (
+                pow10(exp).and_then(|multiplier| base_mantissa.checked_mul(multiplier)),
+                Some(quote_mantissa),
+            )
+        } else {
+            (
+                Some(base_mantissa),
+                pow10(-exp).and_then(|multiplier| quote_mantissa.checked_mul(multiplier)),
+            )
+        };
+        let (numerator, denominator) = match (numerator, denominator) {
+            (Some(n), Some(d)) if d < 0 => (n.checked_neg(), d.checked_neg()),
+            (n, d) => (n, d),
+        };
+        numerator
+            .zip(denominator)
+            .and_then(|(n, d)| Rounding::HalfEven.div(n, d))
+            .map(|mantissa| Self::new(mantissa, out_decimals))
+            .ok_or(DecimalError::IntegerOverflowError)
+    }
+
+    /// Returns the sum of two decimals.
+    pub fn checked_add(self, other: Self) -> Result<Self, DecimalError> {
+        Self::checked_op(self, other, |a, b| a.checked_add(b))
+    }
+
+    /// Returns the difference of two decimals.
+    pub fn checked_sub(self, other: Self) -> Result<Self, DecimalError> {
+        Self::checked_op(self, other, |a, b| a.checked_sub(b))
+    }
+
+    /// Returns the product of two decimals. Digits beyond 28 decimal places are rounded.
+    pub fn checked_mul(self, other: Self) -> Result<Self, DecimalError> {
+        Self::checked_op(self, other, |a, b| a.checked_mul(b))
+    }
+
+    /// Returns the quotient of two decimals. Errors on division by zero.
+    pub fn checked_div(self, other: Self) -> Result<Self, DecimalError> {
+        Self::checked_op(self, other, |a, b| a.checked_div(b))
+    }
+
+    /// Returns the decimal raised to an integer power.
+    pub fn checked_powi(self, exp: i64) -> Result<Self, DecimalError> {
+        let dec: Decimal = self.try_into()?;
+        dec.checked_powi(exp)
+            .map(Self::from)
+            .ok_or(DecimalError::IntegerOverflowError)
+    }
+
+    /// Returns the square root of the decimal. Errors if the decimal is negative.
+    pub fn checked_sqrt(self) -> Result<Self, DecimalError> {
+        let dec: Decimal = self.try_into()?;
+        dec.sqrt()
+            .map(Self::from)
+            .ok_or(DecimalError::DecimalConversionError)
+    }
+
+    /// Returns the absolute value of the decimal.
+    pub fn abs(self) -> Result<Self, DecimalError> {
+        let mantissa = self.mantissa;
+        Ok(Self::new(
+            mantissa
+                .checked_abs()
+                .ok_or(DecimalError::IntegerOverflowError)?,
+            self.scale,
+        ))
+    }
+
+    /// Returns the negated decimal.
+    pub fn checked_neg(self) -> Result<Self, DecimalError> {
+        let mantissa = self.mantissa;
+        Ok(Self::new(
+            mantissa
+                .checked_neg()
+                .ok_or(DecimalError::IntegerOverflowError)?,
+            self.scale,
+        ))
+    }
+
+    /// Returns the smaller of two decimals.
+    pub fn checked_min(self, other: Self) -> Self {
+        std::cmp::min(self, other)
+    }
+
+    /// Returns the larger of two decimals.
+    pub fn checked_max(self, other: Self) -> Self {
+        std::cmp::max(self, other)
+    }
+
+    /// Compares two decimals by normalizing both mantissas to the larger scale. Errors if the
+    /// normalized mantissa overflows an i128.
+    pub fn try_cmp(&self, other: &Self) -> Result<Ordering, DecimalError> {
+        let scale = self.scale.max(other.scale);
+        let a = self.checked_scale_to(scale, Rounding::TowardZero)?;
+        let b = other.checked_scale_to(scale, Rounding::TowardZero)?;
+        Ok(a.cmp(&b))
+    }
+
+    /// Returns whether the decimal rounds to a non-zero integer, rounding half to even.
+    pub fn to_bool(&self) -> bool {
+        let scale = self.scale;
+        if scale == 0 {
+            return self.mantissa != 0;
+        }
+        // half to even only rounds magnitudes above one half away from zero; a half that
+        // overflows a u128 is larger than any mantissa
+        match 10_u128
+            .checked_pow(scale - 1)
+            .and_then(|pow| pow.checked_mul(5))
+        {
+            Some(half) => self.mantissa.unsigned_abs() > half,
+            None => false,
+        }
+    }
+
+    fn checked_op<F>(a: Self, b: Self, op: F) -> Result<Self, DecimalError>
+    where
+        F: FnOnce(Decimal, Decimal) -> Option<Decimal>,
+    {
+        let a: Decimal = a.try_into()?;
+        let b: Decimal = b.try_into()?;
+        op(a, b)
+            .map(Self::from)
+            .ok_or(DecimalError::IntegerOverflowError)
+    }
+}
+impl From<Decimal> for SwitchboardDecimal {
+    fn from(val: Decimal) -> Self {
+        SwitchboardDecimal::new(val.mantissa(), val.scale())
+    }
+}
+impl TryInto<Decimal> for &SwitchboardDecimal {
+    type Error = DecimalError;
+    fn try_into(self) -> Result<Decimal, DecimalError> {
+        Decimal::try_from_i128_with_scale(self.mantissa, self.scale)
+            .map_err(|_| DecimalError::DecimalConversionError)
+    }
+}
+
+impl TryInto<Decimal> for SwitchboardDecimal {
+    type Error = DecimalError;
+    fn try_into(self) -> Result<Decimal, DecimalError> {
+        Decimal::try_from_i128_with_scale(self.mantissa, self.scale)
+            .map_err(|_| DecimalError::DecimalConversionError)
+    }
+}
+
+impl FromStr for SwitchboardDecimal {
+    type Err = DecimalError;
+
+    /// Parses a decimal string exactly, keeping any trailing zeros in the scale. Accepts an
+    /// optional sign and scientific notation, e.g. `-1.25`, `1.5e-3` or `2E6`.
+    fn from_str(s: &str) -> Result<Self, DecimalError> {
+        let s = s.trim();
+        let (negative, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (number, exp) = match unsigned.find(['e', 'E']) {
+            Some(idx) => (
+                &unsigned[..idx],
+                unsigned[idx + 1..]
+                    .parse::<i64>()
+                    .map_err(|_| DecimalError::InvalidStrDecimalConversion)?,
+            ),
+            None => (unsigned, 0),
+        };
+        let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+        if int.is_empty() && frac.is_empty() {
+            return Err(DecimalError::InvalidStrDecimalConversion);
+        }
+
+        // negative values accumulate below zero so i128::MIN, which has no positive
+        // counterpart, still parses
+        let mut mantissa: i128 = 0;
+        for c in int.chars().chain(frac.chars()) {
+            let digit = i128::from(
+                c.to_digit(10)
+                    .ok_or(DecimalError::InvalidStrDecimalConversion)?,
+            );
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| {
+                    if negative {
+                        m.checked_sub(digit)
+                    } else {
+                        m.checked_add(digit)
+                    }
+                })
+                .ok_or(DecimalError::IntegerOverflowError)?;
+        }
+
+        let scale = (frac.len() as i64)
+            .checked_sub(exp)
+            .ok_or(DecimalError::InvalidStrDecimalConversion)?;
+        if scale >= 0 {
+            let scale =
+                u32::try_from(scale).map_err(|_| DecimalError::InvalidStrDecimalConversion)?;
+            return Ok(Self::new(mantissa, scale));
+        }
+        u32::try_from(-scale)
+            .ok()
+            .and_then(|exp| 10_i128.checked_pow(exp))
+            .and_then(|multiplier| mantissa.checked_mul(multiplier))
+            .map(|mantissa| Self::new(mantissa, 0))
+            .ok_or(DecimalError::IntegerOverflowError)
+    }
+}
+
+impl fmt::Display for SwitchboardDecimal {
+    /// Formats the exact value with `scale` decimal places. Scales too large to print
+    /// positionally are written in scientific notation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mantissa = self.mantissa;
+        let scale = self.scale as usize;
+        let digits = mantissa.unsigned_abs().to_string();
+
+        let body = if scale == 0 {
+            digits
+        } else if scale > 64 {
+            format!("{}e-{}", digits, scale)
+        } else if digits.len() > scale {
+            let (int, frac) = digits.split_at(digits.len() - scale);
+            format!("{}.{}", int, frac)
+        } else {
+            format!("0.{}{}", "0".repeat(scale - digits.len()), digits)
+        };
+        f.pad_integral(mantissa >= 0, "", &body)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SwitchboardDecimal {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SwitchboardDecimal {
+    /// Deserializes a decimal string, see [`SwitchboardDecimal::from_str`].
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Ord for SwitchboardDecimal {
+    /// Compares the full i128 mantissas at any scale, see [`SwitchboardDecimal::try_cmp`].
+    fn cmp(&self, other: &Self) -> Ordering {
+        if let Ok(ordering) = self.try_cmp(other) {
+            return ordering;
+        }
+        let (a, b) = (self.mantissa, other.mantissa);
+        // zero is zero at any scale, so a zero on either side or differing signs decide the
+        // ordering by sign alone
+        if a == 0 || b == 0 || a.signum() != b.signum() {
+            return a.signum().cmp(&b.signum());
+        }
+
+        // the integer part of a decimal with a scale above 38 is always zero
+        let integer_part = |mantissa: i128, scale: u32| {
+            10_i128
+                .checked_pow(scale)
+                .map_or(0, |divisor| mantissa / divisor)
+        };
+        match integer_part(a, self.scale).cmp(&integer_part(b, other.scale)) {
+            Ordering::Equal => {
+                // normalizing overflowed, so the non-zero side with the smaller scale is larger
+                // in magnitude than any i128 mantissa at the larger scale
+                let magnitude = { self.scale }.cmp(&{ other.scale }).reverse();
+                if a > 0 {
+                    magnitude
+                } else {
+                    magnitude.reverse()
+                }
+            }
+            ordering => ordering,
+        }
+    }
+}
+
+impl PartialOrd for SwitchboardDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<SwitchboardDecimal> for bool {
+    fn from(s: SwitchboardDecimal) -> Self {
+        s.to_bool()
+    }
+}
+
+impl TryInto<u64> for SwitchboardDecimal {
+    type Error = DecimalError;
+    fn try_into(self) -> Result<u64, DecimalError> {
+        let int = self.checked_scale_to(0, Rounding::TowardZero)?;
+        u64::try_from(int).map_err(|_| DecimalError::IntegerOverflowError)
+    }
+}
+
+impl TryInto<i64> for SwitchboardDecimal {
+    type Error = DecimalError;
+    fn try_into(self) -> Result<i64, DecimalError> {
+        let int = self.checked_scale_to(0, Rounding::TowardZero)?;
+        i64::try_from(int).map_err(|_| DecimalError::IntegerOverflowError)
+    }
+}
+
+impl TryInto<f64> for SwitchboardDecimal {
+    type Error = DecimalError;
+    fn try_into(self) -> Result<f64, DecimalError> {
+        if let Ok(dec) = TryInto::<Decimal>::try_into(&self) {
+            return dec.to_f64().ok_or(DecimalError::IntegerOverflowError);
+        }
+        let mantissa = self.mantissa;
+        let scale = i32::try_from(self.scale).map_err(|_| DecimalError::DecimalConversionError)?;
+        let value = mantissa as f64 / 10_f64.powi(scale);
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(DecimalError::IntegerOverflowError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switchboard_decimal_into_rust_decimal() {
+        let swb_decimal = &SwitchboardDecimal {
+            mantissa: 12345,
+            scale: 2,
+        };
+        let decimal: Decimal = swb_decimal.try_into().unwrap();
+        assert_eq!(decimal.mantissa(), 12345);
+        assert_eq!(decimal.scale(), 2);
+    }
+
+    #[test]
+    fn empty_switchboard_decimal_is_false() {
+        let swb_decimal = SwitchboardDecimal {
+            mantissa: 0,
+            scale: 0,
+        };
+        let b: bool = swb_decimal.into();
+        assert!(!b);
+        let swb_decimal_neg = SwitchboardDecimal {
+            mantissa: -0,
+            scale: 0,
+        };
+        let b: bool = swb_decimal_neg.into();
+        assert!(!b);
+    }
+
+    #[test]
+    fn switchboard_decimal_to_u64() {
+        // 1234.5678
+        let swb_decimal = SwitchboardDecimal {
+            mantissa: 12345678,
+            scale: 4,
+        };
+        let b: u64 = swb_decimal.try_into().unwrap();
+        assert_eq!(b, 1234);
+    }
+
+    #[test]
+    fn switchboard_decimal_to_f64() {
+        // 1234.5678
+        let swb_decimal = SwitchboardDecimal {
+            mantissa: 12345678,
+            scale: 4,
+        };
+        let b: f64 = swb_decimal.try_into().unwrap();
+        assert_eq!(b, 1234.5678);
+
+        let swb_f64 = SwitchboardDecimal::try_from_f64(1234.5678).unwrap();
+        assert_eq!(swb_decimal, swb_f64);
+        for v in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, f64::MAX] {
+            assert_eq!(
+                SwitchboardDecimal::try_from_f64(v).unwrap_err(),
+                DecimalError::DecimalConversionError
+            );
+        }
+    }
+
+    #[test]
+    fn switchboard_decimal_rescale() {
+        let value = SwitchboardDecimal::new(12345, 4);
+        assert_eq!(
+            value.rescale(6, Rounding::Floor).unwrap(),
+            SwitchboardDecimal::new(1234500, 6)
+        );
+        assert_eq!(
+            value.rescale(2, Rounding::Floor).unwrap(),
+            SwitchboardDecimal::new(123, 2)
+        );
+        assert_eq!(
+            value.rescale(2, Rounding::Ceil).unwrap(),
+            SwitchboardDecimal::new(124, 2)
+        );
+        assert_eq!(
+            value.rescale(3, Rounding::HalfEven).unwrap(),
+            SwitchboardDecimal::new(1234, 3)
+        );
+        assert_eq!(
+            value.rescale(3, Rounding::HalfAwayFromZero).unwrap(),
+            SwitchboardDecimal::new(1235, 3)
+        );
+
+        let negative = SwitchboardDecimal::new(-12355, 4);
+        assert_eq!(
+            negative.rescale(2, Rounding::Floor).unwrap(),
+            SwitchboardDecimal::new(-124, 2)
+        );
+        assert_eq!(
+            negative.rescale(2, Rounding::Ceil).unwrap(),
+            SwitchboardDecimal::new(-123, 2)
+        );
+        assert_eq!(
+            negative.rescale(2, Rounding::TowardZero).unwrap(),
+            SwitchboardDecimal::new(-123, 2)
+        );
+        assert_eq!(
+            negative.rescale(3, Rounding::HalfEven).unwrap(),
+            SwitchboardDecimal::new(-1236, 3)
+        );
+
+        // dropping more digits than an i128 can hold
+        assert_eq!(
+            negative.rescale(0, Rounding::Floor).unwrap(),
+            SwitchboardDecimal::new(-2, 0)
+        );
+        let tiny = SwitchboardDecimal::new(-5, 60);
+        assert_eq!(
+            tiny.rescale(0, Rounding::Floor).unwrap(),
+            SwitchboardDecimal::new(-1, 0)
+        );
+        assert_eq!(
+            tiny.rescale(0, Rounding::HalfEven).unwrap(),
+            SwitchboardDecimal::new(0, 0)
+        );
+
+        assert_eq!(
+            SwitchboardDecimal::new(i128::MAX, 0)
+                .rescale(1, Rounding::Floor)
+                .unwrap_err(),
+            DecimalError::IntegerOverflowError
+        );
+        assert_eq!(
+            SwitchboardDecimal::new(0, 0)
+                .rescale(60, Rounding::Floor)
+                .unwrap(),
+            SwitchboardDecimal::new(0, 60)
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn switchboard_decimal_scale_to_saturates() {
+        let value = SwitchboardDecimal::new(-12355, 4);
+        assert_eq!(value.scale_to(2), -123);
+        assert_eq!(value.scale_to(6), -1235500);
+        assert_eq!(value.new_with_scale(2), SwitchboardDecimal::new(-123, 2));
+
+        assert_eq!(SwitchboardDecimal::new(i128::MAX, 0).scale_to(1), i128::MAX);
+        assert_eq!(SwitchboardDecimal::new(-1, 0).scale_to(60), i128::MIN);
+        assert_eq!(
+            SwitchboardDecimal::new(1, 0).new_with_scale(40),
+            SwitchboardDecimal::new(i128::MAX, 40)
+        );
+        assert_eq!(SwitchboardDecimal::new(0, 0).scale_to(60), 0);
+    }
+
+    #[test]
+    fn switchboard_decimal_checked_arithmetic() {
+        let a = SwitchboardDecimal::new(150, 2);
+        let b = SwitchboardDecimal::new(25, 1);
+        let to_decimal = |value: SwitchboardDecimal| -> Decimal { value.try_into().unwrap() };
+
+        assert_eq!(to_decimal(a.checked_add(b).unwrap()), Decimal::new(4, 0));
+        assert_eq!(to_decimal(a.checked_sub(b).unwrap()), Decimal::new(-1, 0));
+        assert_eq!(to_decimal(a.checked_mul(b).unwrap()), Decimal::new(375, 2));
+        assert_eq!(to_decimal(a.checked_div(b).unwrap()), Decimal::new(6, 1));
+        assert_eq!(to_decimal(b.checked_powi(2).unwrap()), Decimal::new(625, 2));
+        assert_eq!(
+            to_decimal(SwitchboardDecimal::new(225, 2).checked_sqrt().unwrap()),
+            Decimal::new(15, 1)
+        );
+        assert_eq!(a.checked_min(b), a);
+        assert_eq!(a.checked_max(b), b);
+        assert_eq!(a.checked_neg().unwrap(), SwitchboardDecimal::new(-150, 2));
+        assert_eq!(a.checked_neg().unwrap().abs().unwrap(), a);
+
+        assert_eq!(
+            a.checked_div(SwitchboardDecimal::default()).unwrap_err(),
+            DecimalError::IntegerOverflowError
+        );
+        assert_eq!(
+            SwitchboardDecimal::new(-1, 0).checked_sqrt().unwrap_err(),
+            DecimalError::DecimalConversionError
+        );
+        assert_eq!(
+            SwitchboardDecimal::new(i128::MAX, 0)
+                .checked_add(a)
+                .unwrap_err(),
+            DecimalError::DecimalConversionError
+        );
+        assert_eq!(
+            SwitchboardDecimal::new(i128::MIN, 0).abs().unwrap_err(),
+            DecimalError::IntegerOverflowError
+        );
+
+        let borsh_a = BorshDecimal::from(a);
+        let borsh_b = BorshDecimal::from(b);
+        assert_eq!(
+            SwitchboardDecimal::from(borsh_a.checked_add(borsh_b).unwrap()),
+            a.checked_add(b).unwrap()
+        );
+    }
+
+    #[test]
+    fn switchboard_decimal_ordering_beyond_rust_decimal() {
+        let huge = SwitchboardDecimal::new(i128::MAX, 0);
+        let tiny = SwitchboardDecimal::new(1, 40);
+        let negative_huge = SwitchboardDecimal::new(i128::MIN, 0);
+
+        assert!(huge > tiny);
+        assert!(negative_huge < tiny);
+        assert!(tiny < huge);
+        assert!(tiny > negative_huge);
+        assert_eq!(
+            huge.try_cmp(&tiny).unwrap_err(),
+            DecimalError::IntegerOverflowError
+        );
+        assert_eq!(
+            SwitchboardDecimal::new(10, 40).cmp(&SwitchboardDecimal::new(1, 39)),
+            Ordering::Equal
+        );
+
+        assert!(bool::from(huge));
+        assert!(!bool::from(SwitchboardDecimal::new(5, 1)));
+        assert!(SwitchboardDecimal::new(15, 1).to_bool());
+        assert!(!SwitchboardDecimal::new(-5, 1).to_bool());
+        assert!(SwitchboardDecimal::new(-6, 1).to_bool());
+        assert!(SwitchboardDecimal::new(i128::MIN, 38).to_bool());
+        assert!(!SwitchboardDecimal::new(i128::MIN, 39).to_bool());
+        assert!(!bool::from(tiny));
+
+        let int: i64 = SwitchboardDecimal::new(-12_345, 40).try_into().unwrap();
+        assert_eq!(int, 0);
+        let result: Result<u64, DecimalError> = huge.try_into();
+        assert_eq!(result.unwrap_err(), DecimalError::IntegerOverflowError);
+        let float: f64 = SwitchboardDecimal::new(15, 40).try_into().unwrap();
+        assert!((float - 1.5e-39).abs() < 1e-50);
+    }
+
+    #[test]
+    fn switchboard_decimal_ordering_matches_rust_decimal() {
+        // xorshift so the sampled mantissas and scales are reproducible
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let mut sample = || {
+            let bits = next() % 97;
+            let magnitude = ((next() as u128) << 64 | next() as u128) >> (128 - bits.max(1));
+            let mantissa = if next() % 2 == 0 {
+                magnitude as i128
+            } else {
+                -(magnitude as i128)
+            };
+            SwitchboardDecimal::new(mantissa, (next() % 29) as u32)
+        };
+
+        for _ in 0..10_000 {
+            let a = sample();
+            let b = sample();
+            let (Ok(dec_a), Ok(dec_b)) = (
+                TryInto::<Decimal>::try_into(a),
+                TryInto::<Decimal>::try_into(b),
+            ) else {
+                continue;
+            };
+            assert_eq!(a.cmp(&b), dec_a.cmp(&dec_b));
+            assert_eq!(bool::from(a), dec_a.round().mantissa() != 0);
+            let int: Result<i64, DecimalError> = a.try_into();
+            assert_eq!(int.ok(), dec_a.to_i64());
+            let float: f64 = a.try_into().unwrap();
+            assert_eq!(float, dec_a.to_f64().unwrap());
+        }
+    }
+
+    #[test]
+    fn switchboard_decimal_ordering_across_scale_gaps() {
+        use num_bigint::BigInt;
+
+        assert!(SwitchboardDecimal::new(0, 0) < SwitchboardDecimal::new(5, 40));
+        assert!(SwitchboardDecimal::new(0, 0) > SwitchboardDecimal::new(-5, 40));
+        assert!(SwitchboardDecimal::new(5, 40) > SwitchboardDecimal::new(0, 0));
+        assert_eq!(
+            SwitchboardDecimal::new(0, 0).cmp(&SwitchboardDecimal::new(0, 60)),
+            Ordering::Equal
+        );
+        assert!(SwitchboardDecimal::new(5, 1) > SwitchboardDecimal::new(i128::MAX, 41));
+        assert!(SwitchboardDecimal::new(-5, 1) < SwitchboardDecimal::new(i128::MIN, 41));
+
+        // the exact value of mantissa * 10^(scale - max_scale), compared as big integers
+        let exact = |value: SwitchboardDecimal, max_scale: u32| {
+            let mantissa = value.mantissa;
+            BigInt::from(mantissa) * BigInt::from(10).pow(max_scale - value.scale)
+        };
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let mut sample = || {
+            let bits = next() % 129;
+            let magnitude = match bits {
+                0 => 0,
+                bits => ((next() as u128) << 64 | next() as u128) >> (128 - bits),
+            };
+            let mantissa = if next() % 2 == 0 {
+                magnitude as i128
+            } else {
+                (magnitude as i128).wrapping_neg()
+            };
+            SwitchboardDecimal::new(mantissa, (next() % 90) as u32)
+        };
+
+        for _ in 0..10_000 {
+            let a = sample();
+            let b = sample();
+            let max_scale = a.scale.max(b.scale);
+            let expected = exact(a, max_scale).cmp(&exact(b, max_scale));
+            assert_eq!(a.cmp(&b), expected, "{:?} {:?}", a, b);
+            assert_eq!(b.cmp(&a), expected.reverse(), "{:?} {:?}", b, a);
+        }
+    }
+
+    #[test]
+    fn switchboard_decimal_fixed_point() {
+        let price = SwitchboardDecimal::new(123_456_789, 6);
+        assert_eq!(price.to_fixed_u64(2, Rounding::Floor).unwrap(), 12_345);
+        assert_eq!(price.to_fixed_u64(2, Rounding::Ceil).unwrap(), 12_346);
+        assert_eq!(
+            price.to_fixed_u64(9, Rounding::Floor).unwrap(),
+            123_456_789_000
+        );
+        assert_eq!(price.to_fixed_u128(0, Rounding::HalfEven).unwrap(), 123);
+        assert_eq!(
+            SwitchboardDecimal::new(-25, 1)
+                .to_fixed_i128(0, Rounding::HalfEven)
+                .unwrap(),
+            -2
+        );
+        assert_eq!(
+            SwitchboardDecimal::new(-1, 0)
+                .to_fixed_u64(6, Rounding::Floor)
+                .unwrap_err(),
+            DecimalError::IntegerOverflowError
+        );
+        assert_eq!(
+            SwitchboardDecimal::from_fixed(1_500_000, 6),
+            SwitchboardDecimal::new(1_500_000, 6)
+        );
+    }
+
+    #[test]
+    fn switchboard_decimal_price_ratio() {
+        let btc_usd = SwitchboardDecimal::new(65_000_125, 3);
+        let eth_usd = SwitchboardDecimal::new(3_250, 0);
+        // 65000.125 / 3250 = 20.0000384615...
+        assert_eq!(
+            SwitchboardDecimal::price_ratio(btc_usd, eth_usd, 6).unwrap(),
+            SwitchboardDecimal::new(20_000_038, 6)
+        );
+        // 3250 / 65000.125 = 0.0499999038...
+        assert_eq!(
+            SwitchboardDecimal::price_ratio(eth_usd, btc_usd, 4).unwrap(),
+            SwitchboardDecimal::new(500, 4)
+        );
+        assert_eq!(
+            SwitchboardDecimal::price_ratio(eth_usd, SwitchboardDecimal::new(-2, 0), 0).unwrap(),
+            SwitchboardDecimal::new(-1_625, 0)
+        );
+        assert!(
+            SwitchboardDecimal::price_ratio(btc_usd, SwitchboardDecimal::default(), 6).is_err()
+        );
+        assert!(
+            SwitchboardDecimal::price_ratio(SwitchboardDecimal::new(i128::MAX, 0), eth_usd, 6)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn switchboard_decimal_from_str() {
+        let parse = |s: &str| SwitchboardDecimal::from_str(s).unwrap();
+        assert_eq!(
+            parse("100.6022611525"),
+            SwitchboardDecimal::new(1006022611525, 10)
+        );
+        assert_eq!(parse("-0.050"), SwitchboardDecimal::new(-50, 3));
+        assert_eq!(parse("+7"), SwitchboardDecimal::new(7, 0));
+        assert_eq!(parse(".5"), SwitchboardDecimal::new(5, 1));
+        assert_eq!(parse("1.5e-3"), SwitchboardDecimal::new(15, 4));
+        assert_eq!(parse("2E6"), SwitchboardDecimal::new(2_000_000, 0));
+        assert_eq!(parse("1.25e1"), SwitchboardDecimal::new(125, 1));
+        assert_eq!(
+            parse("170141183460469231731687303715884105727"),
+            SwitchboardDecimal::new(i128::MAX, 0)
+        );
+
+        for invalid in ["", "-", ".", "1.2.3", "1e", "abc", "1,5", "--1"] {
+            assert_eq!(
+                SwitchboardDecimal::from_str(invalid).unwrap_err(),
+                DecimalError::InvalidStrDecimalConversion,
+                "{}",
+                invalid
+            );
+        }
+        assert_eq!(
+            SwitchboardDecimal::from_str("1e40").unwrap_err(),
+            DecimalError::IntegerOverflowError
+        );
+        assert_eq!(
+            SwitchboardDecimal::from(BorshDecimal::from_str("1.5").unwrap()),
+            SwitchboardDecimal::new(15, 1)
+        );
+    }
+
+    #[test]
+    fn switchboard_decimal_display_round_trips() {
+        let values = [
+            SwitchboardDecimal::new(1006022611525, 10),
+            SwitchboardDecimal::new(-50, 3),
+            SwitchboardDecimal::new(0, 4),
+            SwitchboardDecimal::new(42, 0),
+            SwitchboardDecimal::new(i128::MIN, 20),
+            SwitchboardDecimal::new(-15, 100),
+            SwitchboardDecimal::new(i128::MIN, 0),
+            SwitchboardDecimal::new(i128::MAX, 0),
+        ];
+        let expected = [
+            "100.6022611525",
+            "-0.050",
+            "0.0000",
+            "42",
+            "-1701411834604692317.31687303715884105728",
+            "-15e-100",
+            "-170141183460469231731687303715884105728",
+            "170141183460469231731687303715884105727",
+        ];
+        for (value, expected) in values.iter().zip(expected) {
+            assert_eq!(value.to_string(), expected);
+            assert_eq!(&SwitchboardDecimal::from_str(expected).unwrap(), value);
+        }
+        assert_eq!(
+            SwitchboardDecimal::from_str("-170141183460469231731687303715884105729").unwrap_err(),
+            DecimalError::IntegerOverflowError
+        );
+        assert_eq!(
+            SwitchboardDecimal::from_str("170141183460469231731687303715884105728").unwrap_err(),
+            DecimalError::IntegerOverflowError
+        );
+        assert_eq!(
+            format!("{:>8}", SwitchboardDecimal::new(-15, 1)),
+            "    -1.5"
+        );
+        assert_eq!(
+            BorshDecimal::from(SwitchboardDecimal::new(15, 1)).to_string(),
+            "1.5"
+        );
+    }
+
+    #[test]
+    fn switchboard_decimal_normalize() {
+        assert_eq!(
+            SwitchboardDecimal::new(150_000, 5).normalize(),
+            SwitchboardDecimal::new(15, 1)
+        );
+        assert_eq!(
+            SwitchboardDecimal::new(1_000, 0).normalize(),
+            SwitchboardDecimal::new(1_000, 0)
+        );
+        assert_eq!(
+            SwitchboardDecimal::new(0, 9).normalize(),
+            SwitchboardDecimal::new(0, 0)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn switchboard_decimal_serde() {
+        use serde::de::{value::Error, IntoDeserializer};
+        use serde::Deserialize;
+
+        let value = <SwitchboardDecimal as Deserialize>::deserialize(
+            IntoDeserializer::<Error>::into_deserializer("-1.5e-2"),
+        )
+        .unwrap();
+        assert_eq!(value, SwitchboardDecimal::new(-15, 3));
+        assert!(<SwitchboardDecimal as Deserialize>::deserialize(
+            IntoDeserializer::<Error>::into_deserializer("nope")
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,233 @@
+//! Validation shared by the account loaders of the anchor crates.
+
+use crate::layout::{
+    AccountLayout, AGGREGATOR_HISTORY_ROW, CRANK_ROW, VRF_POOL_ACCOUNT_DATA, VRF_POOL_ROW,
+    VRF_POOL_SIZE,
+};
+use core::fmt;
+
+/// Discriminator used for Switchboard buffer accounts.
+pub const BUFFER_DISCRIMINATOR: [u8; 8] = *b"BUFFERxx";
+
+/// The reason account data could not be decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The account data is shorter than a discriminator.
+    DiscriminatorNotFound,
+    /// The account data does not start with the expected discriminator.
+    DiscriminatorMismatch,
+    /// The account data is too small to hold the account.
+    AccountTooSmall,
+    /// A history buffer's insertion index is outside of its rows.
+    InvalidInsertionIndex,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            DecodeError::DiscriminatorNotFound => "account discriminator not found",
+            DecodeError::DiscriminatorMismatch => "account discriminator did not match",
+            DecodeError::AccountTooSmall => "account data is too small for the account layout",
+            DecodeError::InvalidInsertionIndex => "history buffer insertion index is out of range",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Verifies the account data starts with the expected discriminator.
+pub fn check_discriminator(data: &[u8], discriminator: &[u8; 8]) -> Result<(), DecodeError> {
+    if data.len() < discriminator.len() {
+        return Err(DecodeError::DiscriminatorNotFound);
+    }
+    if data[..discriminator.len()] != discriminator[..] {
+        return Err(DecodeError::DiscriminatorMismatch);
+    }
+    Ok(())
+}
+
+/// Returns the bytes of the account following its discriminator, sized to the layout.
+///
+/// # Arguments
+///
+/// * `data` - A Solana AccountInfo's data buffer
+/// * `layout` - The layout of the expected account
+pub fn account_data<'a>(data: &'a [u8], layout: &AccountLayout) -> Result<&'a [u8], DecodeError> {
+    let start = match layout.discriminator {
+        Some(discriminator) => {
+            check_discriminator(data, &discriminator)?;
+            discriminator.len()
+        }
+        None => 0,
+    };
+    data.get(start..start + layout.size)
+        .ok_or(DecodeError::AccountTooSmall)
+}
+
+/// Returns the insertion index and row bytes of an aggregator history buffer.
+///
+/// Any trailing bytes that do not form a complete row are excluded.
+pub fn history_buffer(data: &[u8]) -> Result<(usize, &[u8]), DecodeError> {
+    check_discriminator(data, &BUFFER_DISCRIMINATOR)?;
+    let idx_bytes: [u8; 4] = data
+        .get(8..12)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(DecodeError::AccountTooSmall)?;
+    let insertion_idx = u32::from_le_bytes(idx_bytes) as usize;
+
+    let rows = &data[12..];
+    let num_rows = rows.len() / AGGREGATOR_HISTORY_ROW.size;
+    if insertion_idx >= num_rows {
+        return Err(DecodeError::InvalidInsertionIndex);
+    }
+    Ok((
+        insertion_idx,
+        &rows[..num_rows * AGGREGATOR_HISTORY_ROW.size],
+    ))
+}
+
+/// Returns the bytes of the first `size` oracle pubkeys held by an oracle queue's data buffer.
+///
+/// # Arguments
+///
+/// * `data` - The data buffer account's data
+/// * `size` - The number of oracles on the queue, the queue's `size` field
+pub fn queue_buffer(data: &[u8], size: usize) -> Result<&[u8], DecodeError> {
+    check_discriminator(data, &BUFFER_DISCRIMINATOR)?;
+    data.get(8..8 + size * 32)
+        .ok_or(DecodeError::AccountTooSmall)
+}
+
+/// Returns the bytes of the first `pq_size` rows held by a crank's data buffer.
+///
+/// # Arguments
+///
+/// * `data` - The data buffer account's data
+/// * `pq_size` - The number of rows on the crank, the crank's `pq_size` field
+pub fn crank_buffer(data: &[u8], pq_size: usize) -> Result<&[u8], DecodeError> {
+    check_discriminator(data, &BUFFER_DISCRIMINATOR)?;
+    data.get(8..8 + pq_size * CRANK_ROW.size)
+        .ok_or(DecodeError::AccountTooSmall)
+}
+
+/// Returns the bytes of the populated rows following a VrfPool account header.
+pub fn vrf_pool_rows(data: &[u8]) -> Result<&[u8], DecodeError> {
+    let header = account_data(data, &VRF_POOL_ACCOUNT_DATA)?;
+    let size_bytes: [u8; 4] = header
+        [VRF_POOL_SIZE.offset..VRF_POOL_SIZE.offset + VRF_POOL_SIZE.size]
+        .try_into()
+        .map_err(|_| DecodeError::AccountTooSmall)?;
+    let size = u32::from_le_bytes(size_bytes) as usize;
+
+    let start = VRF_POOL_ACCOUNT_DATA.account_size();
+    data.get(start..start + size * VRF_POOL_ROW.size)
+        .ok_or(DecodeError::AccountTooSmall)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{LEASE_ACCOUNT_DATA, SWITCHBOARD_DECIMAL};
+
+    #[test]
+    fn test_account_data() {
+        let mut data = LEASE_ACCOUNT_DATA.discriminator.unwrap().to_vec();
+        data.resize(LEASE_ACCOUNT_DATA.account_size() + 3, 1);
+
+        let account = account_data(&data, &LEASE_ACCOUNT_DATA).unwrap();
+        assert_eq!(account.len(), LEASE_ACCOUNT_DATA.size);
+
+        assert_eq!(
+            account_data(&data[..100], &LEASE_ACCOUNT_DATA),
+            Err(DecodeError::AccountTooSmall)
+        );
+        assert_eq!(
+            account_data(&data[..4], &LEASE_ACCOUNT_DATA),
+            Err(DecodeError::DiscriminatorNotFound)
+        );
+        data[0] ^= 1;
+        assert_eq!(
+            account_data(&data, &LEASE_ACCOUNT_DATA),
+            Err(DecodeError::DiscriminatorMismatch)
+        );
+        // nested types have no discriminator
+        assert_eq!(
+            account_data(&data, &SWITCHBOARD_DECIMAL).unwrap(),
+            &data[..20]
+        );
+    }
+
+    #[test]
+    fn test_history_buffer() {
+        let mut data = BUFFER_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.resize(12 + 3 * AGGREGATOR_HISTORY_ROW.size + 5, 0);
+
+        let (insertion_idx, rows) = history_buffer(&data).unwrap();
+        assert_eq!(insertion_idx, 2);
+        assert_eq!(rows.len(), 3 * AGGREGATOR_HISTORY_ROW.size);
+
+        data[8] = 3;
+        assert_eq!(
+            history_buffer(&data),
+            Err(DecodeError::InvalidInsertionIndex)
+        );
+        assert_eq!(
+            history_buffer(&data[..10]),
+            Err(DecodeError::AccountTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_vrf_pool_rows() {
+        let mut data = VRF_POOL_ACCOUNT_DATA.discriminator.unwrap().to_vec();
+        data.resize(
+            VRF_POOL_ACCOUNT_DATA.account_size() + 4 * VRF_POOL_ROW.size,
+            0,
+        );
+        let size_idx = VRF_POOL_ACCOUNT_DATA.account_size() - VRF_POOL_ACCOUNT_DATA.size
+            + VRF_POOL_SIZE.offset;
+        data[size_idx] = 3;
+
+        let rows = vrf_pool_rows(&data).unwrap();
+        assert_eq!(rows.len(), 3 * VRF_POOL_ROW.size);
+
+        data[size_idx] = 5;
+        assert_eq!(vrf_pool_rows(&data), Err(DecodeError::AccountTooSmall));
+        data[0] ^= 1;
+        assert_eq!(
+            vrf_pool_rows(&data),
+            Err(DecodeError::DiscriminatorMismatch)
+        );
+    }
+
+    #[test]
+    fn test_queue_buffer() {
+        let mut data = BUFFER_DISCRIMINATOR.to_vec();
+        data.resize(8 + 4 * 32, 1);
+
+        assert_eq!(queue_buffer(&data, 3).unwrap().len(), 3 * 32);
+        assert_eq!(queue_buffer(&data, 0).unwrap().len(), 0);
+        assert_eq!(queue_buffer(&data, 5), Err(DecodeError::AccountTooSmall));
+        data[0] ^= 1;
+        assert_eq!(
+            queue_buffer(&data, 3),
+            Err(DecodeError::DiscriminatorMismatch)
+        );
+    }
+
+    #[test]
+    fn test_crank_buffer() {
+        let mut data = BUFFER_DISCRIMINATOR.to_vec();
+        data.resize(8 + 4 * CRANK_ROW.size, 1);
+
+        assert_eq!(crank_buffer(&data, 2).unwrap().len(), 2 * CRANK_ROW.size);
+        assert_eq!(crank_buffer(&data, 5), Err(DecodeError::AccountTooSmall));
+        data[0] ^= 1;
+        assert_eq!(
+            crank_buffer(&data, 2),
+            Err(DecodeError::DiscriminatorMismatch)
+        );
+    }
+}
@@ -0,0 +1,540 @@
+//! The on-chain layout of every Switchboard V2 zero-copy account and the types nested in them.
+//!
+//! Offsets are relative to the start of the struct, after the 8 byte account discriminator.
+
+/// The offset and size of a field within a zero-copy struct.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldLayout {
+    pub name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+}
+
+/// The layout of a zero-copy account, or of a zero-copy type nested in one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccountLayout {
+    pub name: &'static str,
+    /// The anchor account discriminator, or None for nested types.
+    pub discriminator: Option<[u8; 8]>,
+    /// The size of the struct, excluding the discriminator.
+    pub size: usize,
+    /// Every field in declaration order.
+    pub fields: &'static [FieldLayout],
+}
+
+impl AccountLayout {
+    /// Returns the minimum size of the account data, including the discriminator.
+    pub const fn account_size(&self) -> usize {
+        match self.discriminator {
+            Some(discriminator) => discriminator.len() + self.size,
+            None => self.size,
+        }
+    }
+
+    /// Returns the layout of the named field.
+    pub fn field(&self, name: &str) -> Option<&FieldLayout> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+const fn field(name: &'static str, offset: usize, size: usize) -> FieldLayout {
+    FieldLayout { name, offset, size }
+}
+
+/// Returns the size of the value behind a pointer without reading it. Used by [`assert_layout!`](crate::assert_layout).
+#[doc(hidden)]
+pub const fn size_of_pointee<T>(_: *const T) -> usize {
+    core::mem::size_of::<T>()
+}
+
+/// The `SwitchboardDecimal` type, a 20-byte i128 mantissa and u32 scale.
+pub const SWITCHBOARD_DECIMAL: AccountLayout = AccountLayout {
+    name: "SwitchboardDecimal",
+    discriminator: None,
+    size: 20,
+    fields: &[field("mantissa", 0, 16), field("scale", 16, 4)],
+};
+
+/// The `Hash` type nested in aggregator accounts.
+pub const HASH: AccountLayout = AccountLayout {
+    name: "Hash",
+    discriminator: None,
+    size: 32,
+    fields: &[field("data", 0, 32)],
+};
+
+/// The `AggregatorRound` type nested in aggregator accounts.
+pub const AGGREGATOR_ROUND: AccountLayout = AccountLayout {
+    name: "AggregatorRound",
+    discriminator: None,
+    size: 1097,
+    fields: &[
+        field("num_success", 0, 4),
+        field("num_error", 4, 4),
+        field("is_closed", 8, 1),
+        field("round_open_slot", 9, 8),
+        field("round_open_timestamp", 17, 8),
+        field("result", 25, 20),
+        field("std_deviation", 45, 20),
+        field("min_response", 65, 20),
+        field("max_response", 85, 20),
+        field("oracle_pubkeys_data", 105, 512),
+        field("medians_data", 617, 320),
+        field("current_payout", 937, 128),
+        field("medians_fulfilled", 1065, 16),
+        field("errors_fulfilled", 1081, 16),
+    ],
+};
+
+/// The `AggregatorAccountData` account.
+pub const AGGREGATOR_ACCOUNT_DATA: AccountLayout = AccountLayout {
+    name: "AggregatorAccountData",
+    discriminator: Some([217, 230, 65, 101, 201, 162, 27, 125]),
+    size: 3843,
+    fields: &[
+        field("name", 0, 32),
+        field("metadata", 32, 128),
+        field("_reserved1", 160, 32),
+        field("queue_pubkey", 192, 32),
+        field("oracle_request_batch_size", 224, 4),
+        field("min_oracle_results", 228, 4),
+        field("min_job_results", 232, 4),
+        field("min_update_delay_seconds", 236, 4),
+        field("start_after", 240, 8),
+        field("variance_threshold", 248, 20),
+        field("force_report_period", 268, 8),
+        field("expiration", 276, 8),
+        field("consecutive_failure_count", 284, 8),
+        field("next_allowed_update_time", 292, 8),
+        field("is_locked", 300, 1),
+        field("crank_pubkey", 301, 32),
+        field("latest_confirmed_round", 333, 1097),
+        field("current_round", 1430, 1097),
+        field("job_pubkeys_data", 2527, 512),
+        field("job_hashes", 3039, 512),
+        field("job_pubkeys_size", 3551, 4),
+        field("jobs_checksum", 3555, 32),
+        field("authority", 3587, 32),
+        field("history_buffer", 3619, 32),
+        field("previous_confirmed_round_result", 3651, 20),
+        field("previous_confirmed_round_slot", 3671, 8),
+        field("disable_crank", 3679, 1),
+        field("job_weights", 3680, 16),
+        field("creation_timestamp", 3696, 8),
+        field("resolution_mode", 3704, 1),
+        field("base_priority_fee", 3705, 4),
+        field("priority_fee_bump", 3709, 4),
+        field("priority_fee_bump_period", 3713, 4),
+        field("max_priority_fee_multiplier", 3717, 4),
+        field("parent_function", 3721, 32),
+        field("_ebuf", 3753, 90),
+    ],
+};
+
+/// An `AggregatorHistoryRow` in an aggregator's history buffer.
+pub const AGGREGATOR_HISTORY_ROW: AccountLayout = AccountLayout {
+    name: "AggregatorHistoryRow",
+    discriminator: None,
+    size: 28,
+    fields: &[field("timestamp", 0, 8), field("value", 8, 20)],
+};
+
+/// A `SlidingWindowElement` in a sliding result account.
+pub const SLIDING_WINDOW_ELEMENT: AccountLayout = AccountLayout {
+    name: "SlidingWindowElement",
+    discriminator: None,
+    size: 68,
+    fields: &[
+        field("oracle_key", 0, 32),
+        field("value", 32, 20),
+        field("slot", 52, 8),
+        field("timestamp", 60, 8),
+    ],
+};
+
+/// The `SlidingResultAccountData` account.
+pub const SLIDING_RESULT_ACCOUNT_DATA: AccountLayout = AccountLayout {
+    name: "SlidingResultAccountData",
+    discriminator: Some([91, 4, 83, 187, 102, 216, 153, 254]),
+    size: 1601,
+    fields: &[
+        field("data", 0, 1088),
+        field("bump", 1088, 1),
+        field("_ebuf", 1089, 512),
+    ],
+};
+
+/// The `OracleQueueAccountData` account.
+pub const ORACLE_QUEUE_ACCOUNT_DATA: AccountLayout = AccountLayout {
+    name: "OracleQueueAccountData",
+    discriminator: Some([164, 207, 200, 51, 199, 113, 35, 109]),
+    size: 1261,
+    fields: &[
+        field("name", 0, 32),
+        field("metadata", 32, 64),
+        field("authority", 96, 32),
+        field("oracle_timeout", 128, 4),
+        field("reward", 132, 8),
+        field("min_stake", 140, 8),
+        field("slashing_enabled", 148, 1),
+        field("variance_tolerance_multiplier", 149, 20),
+        field("feed_probation_period", 169, 4),
+        field("curr_idx", 173, 4),
+        field("size", 177, 4),
+        field("gc_idx", 181, 4),
+        field("consecutive_feed_failure_limit", 185, 8),
+        field("consecutive_oracle_failure_limit", 193, 8),
+        field("unpermissioned_feeds_enabled", 201, 1),
+        field("unpermissioned_vrf_enabled", 202, 1),
+        field("curator_reward_cut", 203, 20),
+        field("lock_lease_funding", 223, 1),
+        field("mint", 224, 32),
+        field("enable_buffer_relayers", 256, 1),
+        field("_ebuf", 257, 968),
+        field("max_size", 1225, 4),
+        field("data_buffer", 1229, 32),
+    ],
+};
+
+/// The `OracleMetrics` type nested in oracle accounts.
+pub const ORACLE_METRICS: AccountLayout = AccountLayout {
+    name: "OracleMetrics",
+    discriminator: None,
+    size: 104,
+    fields: &[
+        field("consecutive_success", 0, 8),
+        field("consecutive_error", 8, 8),
+        field("consecutive_disagreement", 16, 8),
+        field("consecutive_late_response", 24, 8),
+        field("consecutive_failure", 32, 8),
+        field("total_success", 40, 16),
+        field("total_error", 56, 16),
+        field("total_disagreement", 72, 16),
+        field("total_late_response", 88, 16),
+    ],
+};
+
+/// The `OracleAccountData` account.
+pub const ORACLE_ACCOUNT_DATA: AccountLayout = AccountLayout {
+    name: "OracleAccountData",
+    discriminator: Some([128, 30, 16, 241, 170, 73, 55, 54]),
+    size: 628,
+    fields: &[
+        field("name", 0, 32),
+        field("metadata", 32, 128),
+        field("oracle_authority", 160, 32),
+        field("last_heartbeat", 192, 8),
+        field("num_in_use", 200, 4),
+        field("token_account", 204, 32),
+        field("queue_pubkey", 236, 32),
+        field("metrics", 268, 104),
+        field("bump", 372, 1),
+        field("_ebuf", 373, 255),
+    ],
+};
+
+/// The `LeaseAccountData` account.
+pub const LEASE_ACCOUNT_DATA: AccountLayout = AccountLayout {
+    name: "LeaseAccountData",
+    discriminator: Some([55, 254, 208, 251, 164, 44, 150, 50]),
+    size: 445,
+    fields: &[
+        field("escrow", 0, 32),
+        field("queue", 32, 32),
+        field("aggregator", 64, 32),
+        field("token_program", 96, 32),
+        field("is_active", 128, 1),
+        field("crank_row_count", 129, 4),
+        field("created_at", 133, 8),
+        field("update_count", 141, 16),
+        field("withdraw_authority", 157, 32),
+        field("bump", 189, 1),
+        field("_ebuf", 190, 255),
+    ],
+};
+
+/// The `PermissionAccountData` account.
+pub const PERMISSION_ACCOUNT_DATA: AccountLayout = AccountLayout {
+    name: "PermissionAccountData",
+    discriminator: Some([77, 37, 177, 164, 38, 39, 34, 109]),
+    size: 364,
+    fields: &[
+        field("authority", 0, 32),
+        field("permissions", 32, 4),
+        field("granter", 36, 32),
+        field("grantee", 68, 32),
+        field("expiration", 100, 8),
+        field("bump", 108, 1),
+        field("_ebuf", 109, 255),
+    ],
+};
+
+/// A `CrankRow` in a crank's data buffer.
+pub const CRANK_ROW: AccountLayout = AccountLayout {
+    name: "CrankRow",
+    discriminator: None,
+    size: 40,
+    fields: &[field("pubkey", 0, 32), field("next_timestamp", 32, 8)],
+};
+
+/// The `CrankAccountData` account.
+pub const CRANK_ACCOUNT_DATA: AccountLayout = AccountLayout {
+    name: "CrankAccountData",
+    discriminator: Some([111, 81, 146, 73, 172, 180, 134, 209]),
+    size: 424,
+    fields: &[
+        field("name", 0, 32),
+        field("metadata", 32, 64),
+        field("queue_pubkey", 96, 32),
+        field("pq_size", 128, 4),
+        field("max_rows", 132, 4),
+        field("jitter_modifier", 136, 1),
+        field("_ebuf", 137, 255),
+        field("data_buffer", 392, 32),
+    ],
+};
+
+/// The `SbState` program state account.
+pub const SB_STATE: AccountLayout = AccountLayout {
+    name: "SbState",
+    discriminator: Some([159, 42, 192, 191, 139, 62, 168, 28]),
+    size: 1120,
+    fields: &[
+        field("authority", 0, 32),
+        field("token_mint", 32, 32),
+        field("token_vault", 64, 32),
+        field("dao_mint", 96, 32),
+        field("bump", 128, 1),
+        field("_ebuf", 129, 991),
+    ],
+};
+
+/// The `AccountMetaZC` type nested in VRF callbacks.
+pub const ACCOUNT_META_ZC: AccountLayout = AccountLayout {
+    name: "AccountMetaZC",
+    discriminator: None,
+    size: 34,
+    fields: &[
+        field("pubkey", 0, 32),
+        field("is_signer", 32, 1),
+        field("is_writable", 33, 1),
+    ],
+};
+
+/// The `CallbackZC` type nested in VRF accounts.
+pub const CALLBACK_ZC: AccountLayout = AccountLayout {
+    name: "CallbackZC",
+    discriminator: None,
+    size: 2152,
+    fields: &[
+        field("program_id", 0, 32),
+        field("accounts", 32, 1088),
+        field("accounts_len", 1120, 4),
+        field("ix_data", 1124, 1024),
+        field("ix_data_len", 2148, 4),
+    ],
+};
+
+/// The `VrfRound` type nested in VRF accounts.
+pub const VRF_ROUND: AccountLayout = AccountLayout {
+    name: "VrfRound",
+    discriminator: None,
+    size: 568,
+    fields: &[
+        field("alpha", 0, 256),
+        field("alpha_len", 256, 4),
+        field("request_slot", 260, 8),
+        field("request_timestamp", 268, 8),
+        field("result", 276, 32),
+        field("num_verified", 308, 4),
+        field("_ebuf", 312, 256),
+    ],
+};
+
+/// The `VrfBuilder` type holding the incremental proof verification state of VRF accounts.
+pub const VRF_BUILDER: AccountLayout = AccountLayout {
+    name: "VrfBuilder",
+    discriminator: None,
+    size: 3148,
+    fields: &[
+        field("producer", 0, 32),
+        field("status", 32, 1),
+        field("repr_proof", 33, 80),
+        field("proof", 113, 224),
+        field("Y_point", 337, 32),
+        field("stage", 369, 4),
+        field("stage1_out", 373, 200),
+        field("R_1", 573, 160),
+        field("R_2", 733, 160),
+        field("stage3_out", 893, 200),
+        field("H_point", 1093, 160),
+        field("s_reduced", 1253, 32),
+        field("Y_point_builder", 1285, 120),
+        field("Y_ristretto_point", 1405, 160),
+        field("mul_round", 1565, 1),
+        field("hash_points_round", 1566, 1),
+        field("mul_tmp1", 1567, 160),
+        field("U_point1", 1727, 160),
+        field("U_point2", 1887, 160),
+        field("V_point1", 2047, 160),
+        field("V_point2", 2207, 160),
+        field("U_point", 2367, 160),
+        field("V_point", 2527, 160),
+        field("u1", 2687, 40),
+        field("u2", 2727, 40),
+        field("invertee", 2767, 40),
+        field("y", 2807, 40),
+        field("z", 2847, 40),
+        field("p1_bytes", 2887, 32),
+        field("p2_bytes", 2919, 32),
+        field("p3_bytes", 2951, 32),
+        field("p4_bytes", 2983, 32),
+        field("c_prime_hashbuf", 3015, 16),
+        field("m1", 3031, 40),
+        field("m2", 3071, 40),
+        field("tx_remaining", 3111, 4),
+        field("verified", 3115, 1),
+        field("result", 3116, 32),
+    ],
+};
+
+/// The `VrfAccountData` account.
+pub const VRF_ACCOUNT_DATA: AccountLayout = AccountLayout {
+    name: "VrfAccountData",
+    discriminator: Some([101, 35, 62, 239, 103, 151, 6, 18]),
+    size: 29050,
+    fields: &[
+        field("status", 0, 1),
+        field("counter", 1, 16),
+        field("authority", 17, 32),
+        field("oracle_queue", 49, 32),
+        field("escrow", 81, 32),
+        field("callback", 113, 2152),
+        field("batch_size", 2265, 4),
+        field("builders", 2269, 25184),
+        field("builders_len", 27453, 4),
+        field("test_mode", 27457, 1),
+        field("current_round", 27458, 568),
+        field("_ebuf", 28026, 1024),
+    ],
+};
+
+/// The `VrfLiteAccountData` account.
+pub const VRF_LITE_ACCOUNT_DATA: AccountLayout = AccountLayout {
+    name: "VrfLiteAccountData",
+    discriminator: Some([98, 127, 126, 124, 166, 81, 97, 100]),
+    size: 5763,
+    fields: &[
+        field("state_bump", 0, 1),
+        field("permission_bump", 1, 1),
+        field("vrf_pool", 2, 32),
+        field("status", 34, 1),
+        field("result", 35, 32),
+        field("counter", 67, 16),
+        field("alpha", 83, 256),
+        field("alpha_len", 339, 4),
+        field("request_slot", 343, 8),
+        field("request_timestamp", 351, 8),
+        field("authority", 359, 32),
+        field("queue", 391, 32),
+        field("escrow", 423, 32),
+        field("callback", 455, 2152),
+        field("builder", 2607, 3148),
+        field("expiration", 5755, 8),
+    ],
+};
+
+/// The `VrfPoolRow` type stored after the VRF pool account header.
+pub const VRF_POOL_ROW: AccountLayout = AccountLayout {
+    name: "VrfPoolRow",
+    discriminator: None,
+    size: 40,
+    fields: &[field("timestamp", 0, 8), field("pubkey", 8, 32)],
+};
+
+/// The `size` field of [`VRF_POOL_ACCOUNT_DATA`], the number of populated rows.
+pub const VRF_POOL_SIZE: FieldLayout = field("size", 104, 4);
+
+/// The `VrfPoolAccountData` account header. Rows of [`VRF_POOL_ROW`] follow it.
+pub const VRF_POOL_ACCOUNT_DATA: AccountLayout = AccountLayout {
+    name: "VrfPoolAccountData",
+    discriminator: Some([86, 67, 58, 9, 46, 21, 101, 248]),
+    size: 248,
+    fields: &[
+        field("authority", 0, 32),
+        field("queue", 32, 32),
+        field("escrow", 64, 32),
+        field("min_interval", 96, 4),
+        field("max_rows", 100, 4),
+        VRF_POOL_SIZE,
+        field("idx", 108, 4),
+        field("state_bump", 112, 1),
+        field("_ebuf", 113, 135),
+    ],
+};
+
+/// Every account with a discriminator.
+pub const ACCOUNTS: &[AccountLayout] = &[
+    AGGREGATOR_ACCOUNT_DATA,
+    SLIDING_RESULT_ACCOUNT_DATA,
+    ORACLE_QUEUE_ACCOUNT_DATA,
+    ORACLE_ACCOUNT_DATA,
+    LEASE_ACCOUNT_DATA,
+    PERMISSION_ACCOUNT_DATA,
+    CRANK_ACCOUNT_DATA,
+    SB_STATE,
+    VRF_ACCOUNT_DATA,
+    VRF_LITE_ACCOUNT_DATA,
+    VRF_POOL_ACCOUNT_DATA,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NESTED: &[AccountLayout] = &[
+        SWITCHBOARD_DECIMAL,
+        HASH,
+        AGGREGATOR_ROUND,
+        AGGREGATOR_HISTORY_ROW,
+        SLIDING_WINDOW_ELEMENT,
+        ORACLE_METRICS,
+        CRANK_ROW,
+        ACCOUNT_META_ZC,
+        CALLBACK_ZC,
+        VRF_ROUND,
+        VRF_BUILDER,
+        VRF_POOL_ROW,
+    ];
+
+    #[test]
+    fn test_fields_are_contiguous() {
+        for layout in ACCOUNTS.iter().chain(NESTED) {
+            let mut offset = 0;
+            for field in layout.fields {
+                assert_eq!(field.offset, offset, "{}.{}", layout.name, field.name);
+                offset += field.size;
+            }
+            assert_eq!(offset, layout.size, "{}", layout.name);
+        }
+    }
+
+    #[test]
+    fn test_vrf_pool_size_field() {
+        assert_eq!(VRF_POOL_ACCOUNT_DATA.field("size"), Some(&VRF_POOL_SIZE));
+    }
+
+    #[test]
+    fn test_account_size() {
+        assert_eq!(AGGREGATOR_ACCOUNT_DATA.account_size(), 3851);
+        assert_eq!(SWITCHBOARD_DECIMAL.account_size(), 20);
+        assert_eq!(
+            AGGREGATOR_ACCOUNT_DATA.field("latest_confirmed_round"),
+            Some(&FieldLayout {
+                name: "latest_confirmed_round",
+                offset: 333,
+                size: 1097,
+            })
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Anchor-agnostic account layouts and decoding for Switchboard V2 accounts.
+//!
+//! The zero-copy account definitions in `switchboard-solana` and `switchboard-v2` are built
+//! against different anchor and solana versions. This crate holds the single on-chain layout
+//! they are checked against, along with the decoding checks their loaders share and the
+//! [`SwitchboardDecimal`] type results are stored in. Only `switchboard-solana` depends on it so
+//! far.
+//!
+//! The optional `anchor` feature adds borsh serialization for the decimal types and converts
+//! [`DecimalError`] into the anchor error of the same name, so `?` works inside programs.
+
+pub mod decimal;
+pub mod decode;
+pub mod layout;
+pub mod types;
+
+pub use decimal::{BorshDecimal, DecimalError, Rounding, SwitchboardDecimal};
+pub use decode::DecodeError;
+pub use layout::{AccountLayout, FieldLayout};
+
+/// Asserts a zero-copy struct matches an [`AccountLayout`]: its size, and the name, offset
+/// and size of every field in declaration order.
+///
+/// # Examples
+///
+/// ```ignore
+/// switchboard_core::assert_layout!(
+///     SwitchboardDecimal,
+///     switchboard_core::layout::SWITCHBOARD_DECIMAL,
+///     [mantissa, scale]
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_layout {
+    ($ty:ty, $layout:expr, [$($field:ident),* $(,)?]) => {{
+        let layout: $crate::AccountLayout = $layout;
+        assert_eq!(
+            ::core::mem::size_of::<$ty>(),
+            layout.size,
+            "{} size",
+            layout.name
+        );
+
+        let uninit = ::core::mem::MaybeUninit::<$ty>::uninit();
+        let base = uninit.as_ptr();
+        let fields: &[(&str, usize, usize)] = &[$({
+            // SAFETY: addr_of! computes the field address without reading or creating a reference
+            let ptr = unsafe { ::core::ptr::addr_of!((*base).$field) };
+            (
+                stringify!($field),
+                ptr as *const u8 as usize - base as *const u8 as usize,
+                $crate::layout::size_of_pointee(ptr),
+            )
+        }),*];
+
+        let expected: Vec<(&str, usize, usize)> = layout
+            .fields
+            .iter()
+            .map(|field| (field.name, field.offset, field.size))
+            .collect();
+        assert_eq!(fields, &expected[..], "{} fields", layout.name);
+    }};
+}
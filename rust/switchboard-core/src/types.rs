@@ -0,0 +1,141 @@
+//! The zero-copy types nested in Switchboard accounts that hold no pubkeys. Types holding a
+//! `Pubkey` stay in the anchor crates, which each pin their own solana-program.
+
+use crate::decimal::SwitchboardDecimal;
+use bytemuck::{Pod, Zeroable};
+
+#[repr(C, packed)]
+#[derive(Default, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Hash {
+    /// The bytes used to derive the hash.
+    pub data: [u8; 32],
+}
+unsafe impl Pod for Hash {}
+unsafe impl Zeroable for Hash {}
+
+#[repr(C, packed)]
+#[derive(Default, Debug, Copy, Clone)]
+pub struct AggregatorHistoryRow {
+    /// The timestamp of the sample.
+    pub timestamp: i64,
+    /// The value of the sample.
+    pub value: SwitchboardDecimal,
+}
+unsafe impl Pod for AggregatorHistoryRow {}
+unsafe impl Zeroable for AggregatorHistoryRow {}
+
+#[repr(C, packed)]
+#[derive(Default, Copy, Clone)]
+pub struct OracleMetrics {
+    /// Number of consecutive successful update request.
+    pub consecutive_success: u64,
+    /// Number of consecutive update request that resulted in an error.
+    pub consecutive_error: u64,
+    /// Number of consecutive update request that resulted in a disagreement with the accepted median result.
+    pub consecutive_disagreement: u64,
+    /// Number of consecutive update request that were posted on-chain late and not included in an accepted result.
+    pub consecutive_late_response: u64,
+    /// Number of consecutive update request that resulted in a failure.
+    pub consecutive_failure: u64,
+    /// Total number of successful update request.
+    pub total_success: u128,
+    /// Total number of update request that resulted in an error.
+    pub total_error: u128,
+    /// Total number of update request that resulted in a disagreement with the accepted median result.
+    pub total_disagreement: u128,
+    /// Total number of update request that were posted on-chain late and not included in an accepted result.
+    pub total_late_response: u128,
+}
+unsafe impl Pod for OracleMetrics {}
+unsafe impl Zeroable for OracleMetrics {}
+
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct VrfRound {
+    /// The alpha bytes used to calculate the VRF proof.
+    pub alpha: [u8; 256],
+    /// The number of bytes in the alpha buffer.
+    pub alpha_len: u32,
+    /// The Slot when the VRF round was opened.
+    pub request_slot: u64,
+    /// The unix timestamp when the VRF round was opened.
+    pub request_timestamp: i64,
+    /// The VRF round result. Will be zeroized if still awaiting fulfillment.
+    pub result: [u8; 32],
+    /// The number of builders who verified the VRF proof.
+    pub num_verified: u32,
+    /// Reserved for future info.
+    pub _ebuf: [u8; 256],
+}
+impl Default for VrfRound {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+unsafe impl Pod for VrfRound {}
+unsafe impl Zeroable for VrfRound {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_layout, layout};
+
+    #[test]
+    fn test_switchboard_decimal_layout() {
+        assert_layout!(
+            SwitchboardDecimal,
+            layout::SWITCHBOARD_DECIMAL,
+            [mantissa, scale]
+        );
+    }
+
+    #[test]
+    fn test_hash_layout() {
+        assert_layout!(Hash, layout::HASH, [data]);
+    }
+
+    #[test]
+    fn test_aggregator_history_row_layout() {
+        assert_layout!(
+            AggregatorHistoryRow,
+            layout::AGGREGATOR_HISTORY_ROW,
+            [timestamp, value]
+        );
+    }
+
+    #[test]
+    fn test_oracle_metrics_layout() {
+        assert_layout!(
+            OracleMetrics,
+            layout::ORACLE_METRICS,
+            [
+                consecutive_success,
+                consecutive_error,
+                consecutive_disagreement,
+                consecutive_late_response,
+                consecutive_failure,
+                total_success,
+                total_error,
+                total_disagreement,
+                total_late_response
+            ]
+        );
+    }
+
+    #[test]
+    fn test_vrf_round_layout() {
+        assert_layout!(
+            VrfRound,
+            layout::VRF_ROUND,
+            [
+                alpha,
+                alpha_len,
+                request_slot,
+                request_timestamp,
+                result,
+                num_verified,
+                _ebuf
+            ]
+        );
+    }
+}
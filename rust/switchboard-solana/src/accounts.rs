@@ -1,5 +1,6 @@
 pub use crate::oracle_program::accounts::{
     AggregatorAccountData, AggregatorHistoryBuffer, BufferRelayerAccountData, CrankAccountData,
     JobAccountData, LeaseAccountData, OracleAccountData, OracleQueueAccountData,
-    PermissionAccountData, SbState, SlidingResultAccountData,
+    PermissionAccountData, SbState, SlidingResultAccountData, VrfAccountData, VrfLiteAccountData,
+    VrfPoolAccountData,
 };
@@ -0,0 +1,592 @@
+//! Off-chain verification of the ECVRF proofs stored in VRF accounts, following
+//! [RFC 9381](https://www.rfc-editor.org/rfc/rfc9381) ECVRF-EDWARDS25519-SHA512.
+//!
+//! The verifier only trusts the oracle's public key, the round's alpha and the 80 byte proof the
+//! oracle submitted. The intermediate state of the on-chain [`VrfBuilder`] is ignored.
+//!
+//! The account helpers only verify the proof. They do not compare the VRF output with the
+//! round's on-chain `result`: which ciphersuite and draft the Switchboard V2 program uses, and how
+//! it derives `result` from the proof, has not been confirmed against a fulfilled account, so the
+//! caller picks the suite and decides what to do with the returned output.
+
+use crate::prelude::*;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar as EdScalar;
+use curve25519_dalek::traits::Identity;
+use num_bigint::BigUint;
+use sha2::{Digest, Sha512};
+
+/// The length of an encoded proof: a 32 byte point, a 16 byte challenge and a 32 byte scalar.
+pub const ECVRF_PROOF_LEN: usize = 80;
+
+/// The length of the VRF output, beta.
+pub const ECVRF_OUTPUT_LEN: usize = 64;
+
+const CHALLENGE_LEN: usize = 16;
+
+/// The domain separation tag of the RFC 9380 edwards25519_XMD:SHA-512_ELL2_NU_ hash to curve suite.
+const ELL2_DST: &[u8] = b"ECVRF_edwards25519_XMD:SHA-512_ELL2_NU_\x04";
+
+/// The Montgomery curve25519 coefficient A.
+const CURVE25519_A: u32 = 486662;
+
+lazy_static::lazy_static! {
+    /// The field prime 2^255 - 19.
+    static ref FIELD_PRIME: BigUint = (BigUint::from(1u8) << 255u32) - 19u8;
+}
+
+/// The RFC 9381 ciphersuites over edwards25519.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EcvrfSuite {
+    /// ECVRF-EDWARDS25519-SHA512-TAI, hashing to the curve by try-and-increment.
+    Edwards25519Sha512Tai,
+    /// ECVRF-EDWARDS25519-SHA512-ELL2, hashing to the curve with Elligator2.
+    Edwards25519Sha512Ell2,
+}
+
+impl EcvrfSuite {
+    /// Returns the single byte identifying the suite in every hash.
+    pub fn suite_string(&self) -> u8 {
+        match self {
+            EcvrfSuite::Edwards25519Sha512Tai => 0x03,
+            EcvrfSuite::Edwards25519Sha512Ell2 => 0x04,
+        }
+    }
+
+    fn encode_to_curve(&self, salt: &[u8], alpha: &[u8]) -> Option<EdwardsPoint> {
+        let point = match self {
+            EcvrfSuite::Edwards25519Sha512Tai => (0..=u8::MAX).find_map(|ctr| {
+                let hash = sha512(&[&[self.suite_string(), 0x01], salt, alpha, &[ctr, 0x00]]);
+                string_to_point(&hash[..32])
+            })?,
+            EcvrfSuite::Edwards25519Sha512Ell2 => {
+                return ell2_encode_to_curve(&[salt, alpha].concat(), ELL2_DST);
+            }
+        };
+        Some(point.mul_by_cofactor())
+    }
+}
+
+/// Verifies an ECVRF proof and returns the VRF output, beta.
+///
+/// # Arguments
+///
+/// * `suite` - The ciphersuite the oracle used to produce the proof.
+/// * `public_key` - The oracle's VRF public key, a compressed edwards25519 point.
+/// * `proof` - The 80 byte proof, pi.
+/// * `alpha` - The input the proof was produced over.
+///
+/// # Examples
+///
+/// ```ignore
+/// use switchboard_solana::{ecvrf_verify, EcvrfSuite};
+///
+/// let beta = ecvrf_verify(EcvrfSuite::Edwards25519Sha512Ell2, &public_key, &proof, alpha)?;
+/// ```
+pub fn ecvrf_verify(
+    suite: EcvrfSuite,
+    public_key: &[u8; 32],
+    proof: &[u8; ECVRF_PROOF_LEN],
+    alpha: &[u8],
+) -> anchor_lang::Result<[u8; ECVRF_OUTPUT_LEN]> {
+    let invalid = || error!(SwitchboardError::VrfInvalidProof);
+
+    let y = string_to_point(public_key)
+        .filter(|y| !y.is_small_order())
+        .ok_or_else(invalid)?;
+    let (gamma, c, s) = decode_proof(proof).ok_or_else(invalid)?;
+    let h = suite
+        .encode_to_curve(public_key, alpha)
+        .ok_or_else(invalid)?;
+
+    // U = s*B - c*Y, V = s*H - c*Gamma
+    let u = EdwardsPoint::vartime_double_scalar_mul_basepoint(&-c, &y, &s);
+    let v = s * h - c * gamma;
+
+    let challenge = sha512(&[
+        &[suite.suite_string(), 0x02],
+        y.compress().as_bytes(),
+        h.compress().as_bytes(),
+        gamma.compress().as_bytes(),
+        u.compress().as_bytes(),
+        v.compress().as_bytes(),
+        &[0x00],
+    ]);
+    if challenge[..CHALLENGE_LEN] != proof[32..32 + CHALLENGE_LEN] {
+        return Err(invalid());
+    }
+
+    ecvrf_proof_to_hash(suite, proof)
+}
+
+/// Returns the VRF output, beta, of a proof without verifying it.
+///
+/// # Arguments
+///
+/// * `suite` - The ciphersuite the oracle used to produce the proof.
+/// * `proof` - The 80 byte proof, pi.
+pub fn ecvrf_proof_to_hash(
+    suite: EcvrfSuite,
+    proof: &[u8; ECVRF_PROOF_LEN],
+) -> anchor_lang::Result<[u8; ECVRF_OUTPUT_LEN]> {
+    let (gamma, _, _) = decode_proof(proof).ok_or(SwitchboardError::VrfInvalidProof)?;
+
+    Ok(sha512(&[
+        &[suite.suite_string(), 0x03],
+        gamma.mul_by_cofactor().compress().as_bytes(),
+        &[0x00],
+    ]))
+}
+
+impl VrfAccountData {
+    /// Verifies the proof submitted by the oracle with the given VRF public key for the current
+    /// round and returns the full VRF output. `current_round.result` is not compared, see the
+    /// [module docs](self).
+    ///
+    /// # Arguments
+    ///
+    /// * `suite` - The ciphersuite the oracle used to produce the proof.
+    /// * `oracle_public_key` - The oracle's VRF public key.
+    /// * `alpha` - The input of the current round.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{EcvrfSuite, VrfAccountData};
+    ///
+    /// let vrf = VrfAccountData::new_from_bytes(&data)?;
+    /// let beta = vrf.verify_current_round(EcvrfSuite::Edwards25519Sha512Ell2, &oracle_key, &alpha)?;
+    /// ```
+    pub fn verify_current_round(
+        &self,
+        suite: EcvrfSuite,
+        oracle_public_key: &Pubkey,
+        alpha: &[u8],
+    ) -> anchor_lang::Result<[u8; ECVRF_OUTPUT_LEN]> {
+        let builders_len = (self.builders_len as usize).min(self.builders.len());
+        let builder = self.builders[..builders_len]
+            .iter()
+            .find(|builder| { builder.Y_point } == *oracle_public_key)
+            .ok_or(SwitchboardError::VrfProofNotFound)?;
+
+        verify_builder_proof(suite, builder, alpha)
+    }
+}
+
+impl VrfLiteAccountData {
+    /// Verifies the proof submitted by the oracle with the given VRF public key for the current
+    /// round and returns the full VRF output. `result` is not compared, see the
+    /// [module docs](self).
+    ///
+    /// # Arguments
+    ///
+    /// * `suite` - The ciphersuite the oracle used to produce the proof.
+    /// * `oracle_public_key` - The oracle's VRF public key.
+    /// * `alpha` - The input of the current round.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{EcvrfSuite, VrfLiteAccountData};
+    ///
+    /// let vrf_lite = VrfLiteAccountData::new_from_bytes(&data)?;
+    /// let beta = vrf_lite.verify_current_round(EcvrfSuite::Edwards25519Sha512Ell2, &oracle_key, &alpha)?;
+    /// ```
+    pub fn verify_current_round(
+        &self,
+        suite: EcvrfSuite,
+        oracle_public_key: &Pubkey,
+        alpha: &[u8],
+    ) -> anchor_lang::Result<[u8; ECVRF_OUTPUT_LEN]> {
+        if { self.builder.Y_point } != *oracle_public_key {
+            return Err(SwitchboardError::VrfProofNotFound.into());
+        }
+
+        verify_builder_proof(suite, &self.builder, alpha)
+    }
+}
+
+fn verify_builder_proof(
+    suite: EcvrfSuite,
+    builder: &VrfBuilder,
+    alpha: &[u8],
+) -> anchor_lang::Result<[u8; ECVRF_OUTPUT_LEN]> {
+    ecvrf_verify(
+        suite,
+        &builder.Y_point.to_bytes(),
+        &builder.repr_proof,
+        alpha,
+    )
+}
+
+fn sha512(parts: &[&[u8]]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    let mut hash = [0u8; 64];
+    hash.copy_from_slice(&hasher.finalize());
+    hash
+}
+
+/// Decodes a point per RFC 8032, rejecting non-canonical encodings.
+fn string_to_point(bytes: &[u8]) -> Option<EdwardsPoint> {
+    let compressed = CompressedEdwardsY::from_slice(bytes);
+    let point = compressed.decompress()?;
+    (point.compress() == compressed).then_some(point)
+}
+
+fn decode_proof(proof: &[u8; ECVRF_PROOF_LEN]) -> Option<(EdwardsPoint, EdScalar, EdScalar)> {
+    let gamma = string_to_point(&proof[..32])?;
+    let mut c = [0u8; 32];
+    c[..CHALLENGE_LEN].copy_from_slice(&proof[32..32 + CHALLENGE_LEN]);
+    let mut s = [0u8; 32];
+    s.copy_from_slice(&proof[32 + CHALLENGE_LEN..]);
+
+    Some((
+        gamma,
+        EdScalar::from_bytes_mod_order(c),
+        EdScalar::from_canonical_bytes(s)?,
+    ))
+}
+
+/// RFC 9380 encode_to_curve for the edwards25519_XMD:SHA-512_ELL2_NU_ suite.
+///
+/// The map is written out over `BigUint` because curve25519-dalek 3, the version solana-program
+/// 1.x resolves, keeps its field arithmetic private, and the curve25519-dalek releases that ship
+/// `EdwardsPoint::encode_to_curve` need a `subtle` release that solana-program 1.x excludes. It is
+/// checked against the RFC 9380 Appendix J.5.2 vectors in the tests below. Nothing here is
+/// constant time, which is fine for verifying public proofs but not for handling secrets.
+fn ell2_encode_to_curve(msg: &[u8], dst: &[u8]) -> Option<EdwardsPoint> {
+    map_to_curve_elligator2(&hash_to_field(msg, dst)).map(|point| point.mul_by_cofactor())
+}
+
+/// RFC 9380 hash_to_field with expand_message_xmd over SHA-512, for a single field element.
+fn hash_to_field(msg: &[u8], dst: &[u8]) -> BigUint {
+    const LEN_IN_BYTES: usize = 48;
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = sha512(&[
+        &[0u8; 128],
+        msg,
+        &(LEN_IN_BYTES as u16).to_be_bytes(),
+        &[0x00],
+        dst,
+        &dst_len,
+    ]);
+    let b_1 = sha512(&[&b_0, &[0x01], dst, &dst_len]);
+
+    BigUint::from_bytes_be(&b_1[..LEN_IN_BYTES]) % &*FIELD_PRIME
+}
+
+/// RFC 9380 map_to_curve_elligator2 over curve25519 followed by the rational map to edwards25519.
+fn map_to_curve_elligator2(u: &BigUint) -> Option<EdwardsPoint> {
+    let a = BigUint::from(CURVE25519_A);
+    let one = BigUint::from(1u8);
+
+    let mut x1 = fe_mul(
+        &fe_neg(&a),
+        &fe_inv(&fe_add(&one, &fe_mul(&BigUint::from(2u8), &fe_mul(u, u)))),
+    );
+    if x1 == BigUint::default() {
+        x1 = fe_neg(&a);
+    }
+    let x2 = fe_neg(&fe_add(&x1, &a));
+    let g = |x: &BigUint| fe_add(&fe_mul(&fe_add(&fe_mul(x, x), &fe_mul(&a, x)), x), x);
+
+    let gx1 = g(&x1);
+    let (s, t) = if fe_is_square(&gx1) {
+        (x1, fe_sqrt(&gx1, true)?)
+    } else {
+        let gx2 = g(&x2);
+        (x2, fe_sqrt(&gx2, false)?)
+    };
+
+    let s_plus_one = fe_add(&s, &one);
+    if t == BigUint::default() || s_plus_one == BigUint::default() {
+        return Some(EdwardsPoint::identity());
+    }
+    let c1 = fe_sqrt(&fe_neg(&BigUint::from(CURVE25519_A + 2)), false)?;
+    let x = fe_mul(&fe_mul(&c1, &s), &fe_inv(&t));
+    let y = fe_mul(&fe_add(&s, &fe_neg(&one)), &fe_inv(&s_plus_one));
+
+    let mut bytes = [0u8; 32];
+    let y_bytes = y.to_bytes_le();
+    bytes[..y_bytes.len()].copy_from_slice(&y_bytes);
+    if x.bit(0) {
+        bytes[31] |= 0x80;
+    }
+    CompressedEdwardsY(bytes).decompress()
+}
+
+fn fe_add(a: &BigUint, b: &BigUint) -> BigUint {
+    (a + b) % &*FIELD_PRIME
+}
+
+fn fe_mul(a: &BigUint, b: &BigUint) -> BigUint {
+    (a * b) % &*FIELD_PRIME
+}
+
+fn fe_neg(a: &BigUint) -> BigUint {
+    (&*FIELD_PRIME - a % &*FIELD_PRIME) % &*FIELD_PRIME
+}
+
+/// Returns the inverse of `a`, or zero if `a` is zero.
+fn fe_inv(a: &BigUint) -> BigUint {
+    a.modpow(&(&*FIELD_PRIME - 2u8), &FIELD_PRIME)
+}
+
+fn fe_is_square(a: &BigUint) -> bool {
+    let legendre = a.modpow(&((&*FIELD_PRIME - 1u8) >> 1), &FIELD_PRIME);
+    legendre <= BigUint::from(1u8)
+}
+
+/// Returns the square root of `a` whose parity matches `odd`, if `a` is a square.
+fn fe_sqrt(a: &BigUint, odd: bool) -> Option<BigUint> {
+    let p = &*FIELD_PRIME;
+    let a = a % p;
+    let candidate = a.modpow(&((p + 3u8) >> 3), p);
+    let root = if fe_mul(&candidate, &candidate) == a {
+        candidate
+    } else if fe_mul(&candidate, &candidate) == fe_neg(&a) {
+        let sqrt_m1 = BigUint::from(2u8).modpow(&((p - 1u8) >> 2), p);
+        fe_mul(&candidate, &sqrt_m1)
+    } else {
+        return None;
+    };
+
+    Some(if root.bit(0) == odd {
+        root
+    } else {
+        fe_neg(&root)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    struct Vector {
+        public_key: &'static str,
+        alpha: &'static str,
+        proof: &'static str,
+        beta: &'static str,
+    }
+
+    const TAI_VECTORS: [Vector; 3] = [
+        Vector {
+            public_key: "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            alpha: "",
+            proof: "8657106690b5526245a92b003bb079ccd1a92130477671f6fc01ad16f26f723f26f8a57ccaed74ee1b190bed1f479d9727d2d0f9b005a6e456a35d4fb0daab1268a1b0db10836d9826a528ca76567805",
+            beta: "90cf1df3b703cce59e2a35b925d411164068269d7b2d29f3301c03dd757876ff66b71dda49d2de59d03450451af026798e8f81cd2e333de5cdf4f3e140fdd8ae",
+        },
+        Vector {
+            public_key: "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            alpha: "72",
+            proof: "f3141cd382dc42909d19ec5110469e4feae18300e94f304590abdced48aed5933bf0864a62558b3ed7f2fea45c92a465301b3bbf5e3e54ddf2d935be3b67926da3ef39226bbc355bdc9850112c8f4b02",
+            beta: "eb4440665d3891d668e7e0fcaf587f1b4bd7fbfe99d0eb2211ccec90496310eb5e33821bc613efb94db5e5b54c70a848a0bef4553a41befc57663b56373a5031",
+        },
+        Vector {
+            public_key: "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            alpha: "af82",
+            proof: "9bc0f79119cc5604bf02d23b4caede71393cedfbb191434dd016d30177ccbf8096bb474e53895c362d8628ee9f9ea3c0e52c7a5c691b6c18c9979866568add7a2d41b00b05081ed0f58ee5e31b3a970e",
+            beta: "645427e5d00c62a23fb703732fa5d892940935942101e456ecca7bb217c61c452118fec1219202a0edcf038bb6373241578be7217ba85a2687f7a0310b2df19f",
+        },
+    ];
+
+    const ELL2_VECTORS: [Vector; 3] = [
+        Vector {
+            public_key: "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            alpha: "",
+            proof: "7d9c633ffeee27349264cf5c667579fc583b4bda63ab71d001f89c10003ab46f14adf9a3cd8b8412d9038531e865c341cafa73589b023d14311c331a9ad15ff2fb37831e00f0acaa6d73bc9997b06501",
+            beta: "9d574bf9b8302ec0fc1e21c3ec5368269527b87b462ce36dab2d14ccf80c53cccf6758f058c5b1c856b116388152bbe509ee3b9ecfe63d93c3b4346c1fbc6c54",
+        },
+        Vector {
+            public_key: "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            alpha: "72",
+            proof: "47b327393ff2dd81336f8a2ef10339112401253b3c714eeda879f12c509072ef055b48372bb82efbdce8e10c8cb9a2f9d60e93908f93df1623ad78a86a028d6bc064dbfc75a6a57379ef855dc6733801",
+            beta: "38561d6b77b71d30eb97a062168ae12b667ce5c28caccdf76bc88e093e4635987cd96814ce55b4689b3dd2947f80e59aac7b7675f8083865b46c89b2ce9cc735",
+        },
+        Vector {
+            public_key: "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            alpha: "af82",
+            proof: "926e895d308f5e328e7aa159c06eddbe56d06846abf5d98c2512235eaa57fdce35b46edfc655bc828d44ad09d1150f31374e7ef73027e14760d42e77341fe05467bb286cc2c9d7fde29120a0b2320d04",
+            beta: "121b7f9b9aaaa29099fc04a94ba52784d44eac976dd1a3cca458733be5cd090a7b5fbd148444f17f8daf1fb55cb04b1ae85a626e30a54b4b0f8abf4a43314a58",
+        },
+    ];
+
+    fn decode(vector: &Vector) -> ([u8; 32], [u8; ECVRF_PROOF_LEN], Vec<u8>, Vec<u8>) {
+        (
+            hex(vector.public_key).try_into().unwrap(),
+            hex(vector.proof).try_into().unwrap(),
+            hex(vector.alpha),
+            hex(vector.beta),
+        )
+    }
+
+    #[test]
+    fn test_rfc9381_vectors() {
+        for (suite, vectors) in [
+            (EcvrfSuite::Edwards25519Sha512Tai, &TAI_VECTORS),
+            (EcvrfSuite::Edwards25519Sha512Ell2, &ELL2_VECTORS),
+        ] {
+            for vector in vectors.iter() {
+                let (public_key, proof, alpha, beta) = decode(vector);
+                assert_eq!(
+                    ecvrf_verify(suite, &public_key, &proof, &alpha).unwrap()[..],
+                    beta[..]
+                );
+                assert_eq!(ecvrf_proof_to_hash(suite, &proof).unwrap()[..], beta[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rfc9380_encode_to_curve_vectors() {
+        // RFC 9380 Appendix J.5.2, edwards25519_XMD:SHA-512_ELL2_NU_, as (msg, P.x, P.y)
+        const DST: &[u8] = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_";
+        let q128 = [b"q128_".as_slice(), &[b'q'; 128]].concat();
+        let a512 = [b"a512_".as_slice(), &[b'a'; 512]].concat();
+        let vectors: [(&[u8], &str, &str); 5] = [
+            (
+                b"",
+                "1ff2b70ecf862799e11b7ae744e3489aa058ce805dd323a936375a84695e76da",
+                "222e314d04a4d5725e9f2aff9fb2a6b69ef375a1214eb19021ceab2d687f0f9b",
+            ),
+            (
+                b"abc",
+                "5f13cc69c891d86927eb37bd4afc6672360007c63f68a33ab423a3aa040fd2a8",
+                "67732d50f9a26f73111dd1ed5dba225614e538599db58ba30aaea1f5c827fa42",
+            ),
+            (
+                b"abcdef0123456789",
+                "1dd2fefce934ecfd7aae6ec998de088d7dd03316aa1847198aecf699ba6613f1",
+                "2f8a6c24dd1adde73909cada6a4a137577b0f179d336685c4a955a0a8e1a86fb",
+            ),
+            (
+                &q128,
+                "35fbdc5143e8a97afd3096f2b843e07df72e15bfca2eaf6879bf97c5d3362f73",
+                "2af6ff6ef5ebba128b0774f4296cb4c2279a074658b083b8dcca91f57a603450",
+            ),
+            (
+                &a512,
+                "6e5e1f37e99345887fc12111575fc1c3e36df4b289b8759d23af14d774b66bff",
+                "2c90c3d39eb18ff291d33441b35f3262cdd307162cc97c31bfcc7a4245891a37",
+            ),
+        ];
+
+        for (msg, x, y) in vectors {
+            // the compressed point is y in little endian with the parity of x in the top bit
+            let mut expected = hex(y);
+            expected.reverse();
+            if hex(x)[31] & 1 == 1 {
+                expected[31] |= 0x80;
+            }
+            let point = ell2_encode_to_curve(msg, DST).unwrap();
+            assert_eq!(point.compress().as_bytes()[..], expected[..]);
+        }
+    }
+
+    #[test]
+    fn test_rejects_invalid_proofs() {
+        let suite = EcvrfSuite::Edwards25519Sha512Ell2;
+        let (public_key, proof, alpha, _) = decode(&ELL2_VECTORS[1]);
+        let invalid: anchor_lang::error::Error = SwitchboardError::VrfInvalidProof.into();
+
+        // wrong alpha, wrong suite and wrong key
+        assert_eq!(
+            ecvrf_verify(suite, &public_key, &proof, b"73").unwrap_err(),
+            invalid
+        );
+        assert_eq!(
+            ecvrf_verify(
+                EcvrfSuite::Edwards25519Sha512Tai,
+                &public_key,
+                &proof,
+                &alpha
+            )
+            .unwrap_err(),
+            invalid
+        );
+        let (other_key, _, _, _) = decode(&ELL2_VECTORS[0]);
+        assert_eq!(
+            ecvrf_verify(suite, &other_key, &proof, &alpha).unwrap_err(),
+            invalid
+        );
+
+        // tampered challenge
+        let mut tampered = proof;
+        tampered[40] ^= 1;
+        assert_eq!(
+            ecvrf_verify(suite, &public_key, &tampered, &alpha).unwrap_err(),
+            invalid
+        );
+
+        // s >= q
+        let mut tampered = proof;
+        tampered[79] = 0xff;
+        assert_eq!(
+            ecvrf_verify(suite, &public_key, &tampered, &alpha).unwrap_err(),
+            invalid
+        );
+
+        // small order public key
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        assert_eq!(
+            ecvrf_verify(suite, &identity, &proof, &alpha).unwrap_err(),
+            invalid
+        );
+    }
+
+    #[test]
+    fn test_verify_current_round() {
+        let suite = EcvrfSuite::Edwards25519Sha512Ell2;
+        let (public_key, proof, alpha, beta) = decode(&ELL2_VECTORS[2]);
+        let oracle_key = Pubkey::new_from_array(public_key);
+
+        let mut vrf_lite = VrfLiteAccountData::default();
+        vrf_lite.builder.Y_point = oracle_key;
+        vrf_lite.builder.repr_proof = proof;
+        assert_eq!(
+            vrf_lite
+                .verify_current_round(suite, &oracle_key, &alpha)
+                .unwrap()[..],
+            beta[..]
+        );
+        assert_eq!(
+            vrf_lite
+                .verify_current_round(suite, &Pubkey::default(), &alpha)
+                .unwrap_err(),
+            SwitchboardError::VrfProofNotFound.into()
+        );
+
+        let mut vrf = VrfAccountData::default();
+        vrf.builders[1] = vrf_lite.builder;
+        assert_eq!(
+            vrf.verify_current_round(suite, &oracle_key, &alpha)
+                .unwrap_err(),
+            SwitchboardError::VrfProofNotFound.into()
+        );
+        vrf.builders_len = 2;
+        assert_eq!(
+            vrf.verify_current_round(suite, &oracle_key, &alpha)
+                .unwrap()[..],
+            beta[..]
+        );
+
+        // the proof must verify over the round's alpha
+        assert_eq!(
+            vrf.verify_current_round(suite, &oracle_key, b"other alpha")
+                .unwrap_err(),
+            SwitchboardError::VrfInvalidProof.into()
+        );
+    }
+}
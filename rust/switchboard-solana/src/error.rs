@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use switchboard_core::DecodeError;
 
 #[error_code]
 #[derive(Eq, PartialEq)]
@@ -67,6 +68,47 @@ pub enum SwitchboardError {
     #[msg("Service is already being executed by a worker. Please remove the service before adding to a new service worker")]
     ServiceAlreadyAssignedToWorker,
     NetworkError,
+    #[msg("Switchboard feed result is outside of the allowed value band")]
+    FeedValueOutOfRange,
+    #[msg(
+        "Sliding window does not contain enough fresh oracle responses to meet min_oracle_results"
+    )]
+    InsufficientFreshSlidingResponses,
+    #[msg("History buffer does not contain any samples for the requested window")]
+    InsufficientHistory,
+    #[msg("Account is not owned by the Switchboard program")]
+    InvalidAccountOwner,
+    #[msg("No VRF builder holds a proof from the given oracle public key")]
+    VrfProofNotFound,
+    #[msg("VRF proof failed verification")]
+    VrfInvalidProof,
+    #[msg("VRF callback exceeds the maximum of 32 accounts")]
+    CallbackTooManyAccounts,
+    #[msg("VRF callback instruction data exceeds the maximum of 1024 bytes")]
+    CallbackIxDataTooLarge,
+    #[msg("Randomness range is empty, the upper bound must be greater than the lower bound")]
+    EmptyRandomnessRange,
+    #[msg("Randomness weights must be non-empty, sum to more than zero and not overflow a u64")]
+    InvalidRandomnessWeights,
+    #[msg("Account is not the oracle queue's data buffer")]
+    InvalidQueueDataBuffer,
+    #[msg("Account is not the crank's data buffer")]
+    InvalidCrankDataBuffer,
+    #[msg("Account is not the aggregator's sliding result account")]
+    InvalidSlidingResultAccount,
+    #[msg("VRF account holds a status the SDK does not recognize")]
+    InvalidVrfStatus,
 }
 
 impl std::error::Error for SwitchboardError {}
+
+/// Maps an error from the shared switchboard-core decoder to the error returned by the loaders.
+pub(crate) fn decode_error(err: DecodeError) -> anchor_lang::error::Error {
+    match err {
+        DecodeError::DiscriminatorNotFound => ErrorCode::AccountDiscriminatorNotFound.into(),
+        DecodeError::DiscriminatorMismatch => ErrorCode::AccountDiscriminatorMismatch.into(),
+        DecodeError::AccountTooSmall | DecodeError::InvalidInsertionIndex => {
+            SwitchboardError::AccountDeserializationError.into()
+        }
+    }
+}
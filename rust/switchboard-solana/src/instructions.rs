@@ -1 +1,6 @@
-pub use crate::oracle_program::instructions::PermissionSet;
+pub use crate::oracle_program::instructions::{
+    PermissionSet, VrfClose, VrfCloseParams, VrfLiteClose, VrfLiteCloseParams,
+    VrfLiteRequestRandomness, VrfLiteRequestRandomnessParams, VrfPoolRemove, VrfPoolRemoveParams,
+    VrfPoolRequestRandomness, VrfPoolRequestRandomnessParams, VrfRequestRandomness,
+    VrfRequestRandomnessParams, VrfSetCallback, VrfSetCallbackParams,
+};
@@ -98,6 +98,8 @@
 
 mod macros;
 
+pub use switchboard_core;
+
 pub mod decimal;
 pub use decimal::*;
 
@@ -109,11 +111,22 @@ pub mod error;
 pub mod seeds;
 pub use seeds::*;
 
+pub mod pda;
+
 pub mod utils;
 pub use utils::*;
 
 pub mod events;
 
+pub mod randomness;
+pub use randomness::*;
+
+#[cfg(not(target_os = "solana"))]
+#[cfg_attr(doc_cfg, doc(cfg(not(target_os = "solana"))))]
+pub mod ecvrf_verify;
+#[cfg(not(target_os = "solana"))]
+pub use ecvrf_verify::*;
+
 pub mod program_id;
 pub use program_id::*;
 
@@ -1,17 +1,11 @@
 use crate::prelude::*;
 use rust_decimal::Decimal;
 use std::cell::Ref;
+pub use switchboard_core::types::Hash;
+use switchboard_core::{decode, layout};
 
 #[zero_copy(unsafe)]
-#[repr(packed)]
-#[derive(Default, Debug, PartialEq, Eq)]
-pub struct Hash {
-    /// The bytes used to derive the hash.
-    pub data: [u8; 32],
-}
-
-#[zero_copy(unsafe)]
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Default, Debug, PartialEq, Eq)]
 pub struct AggregatorRound {
     /// Maintains the number of successful responses received from nodes.
@@ -52,9 +46,82 @@ pub enum AggregatorResolutionMode {
     ModeSlidingResolution = 1,
 }
 
+/// The maximum age of a confirmed round before it is considered stale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StalenessBound {
+    /// Maximum number of seconds since the round was opened, measured against `Clock::unix_timestamp`.
+    Seconds(i64),
+    /// Maximum number of slots since the round was opened, measured against `Clock::slot`.
+    Slots(u64),
+    /// Stale if either the seconds or the slots bound is exceeded.
+    Either { seconds: i64, slots: u64 },
+}
+
+impl StalenessBound {
+    /// Check a round opened at the given timestamp and slot against the clock. On failure the
+    /// error carries the measured age and the bound it exceeded.
+    pub fn check(
+        &self,
+        clock: &Clock,
+        round_open_timestamp: i64,
+        round_open_slot: u64,
+    ) -> anchor_lang::Result<()> {
+        match *self {
+            StalenessBound::Seconds(seconds) => {
+                Self::check_seconds(clock.unix_timestamp, round_open_timestamp, seconds)
+            }
+            StalenessBound::Slots(slots) => Self::check_slots(clock.slot, round_open_slot, slots),
+            StalenessBound::Either { seconds, slots } => {
+                Self::check_seconds(clock.unix_timestamp, round_open_timestamp, seconds)?;
+                Self::check_slots(clock.slot, round_open_slot, slots)
+            }
+        }
+    }
+
+    /// Returns whether a round opened at the given timestamp and slot exceeds the bound.
+    pub fn is_stale(&self, clock: &Clock, round_open_timestamp: i64, round_open_slot: u64) -> bool {
+        let seconds_stale =
+            |seconds: i64| clock.unix_timestamp.saturating_sub(round_open_timestamp) > seconds;
+        let slots_stale = |slots: u64| clock.slot.saturating_sub(round_open_slot) > slots;
+        match *self {
+            StalenessBound::Seconds(seconds) => seconds_stale(seconds),
+            StalenessBound::Slots(slots) => slots_stale(slots),
+            StalenessBound::Either { seconds, slots } => {
+                seconds_stale(seconds) || slots_stale(slots)
+            }
+        }
+    }
+
+    pub(crate) fn check_seconds(
+        unix_timestamp: i64,
+        round_open_timestamp: i64,
+        max_staleness: i64,
+    ) -> anchor_lang::Result<()> {
+        let staleness = unix_timestamp.saturating_sub(round_open_timestamp);
+        if staleness > max_staleness {
+            msg!("Feed has not been updated in {} seconds!", staleness);
+            return Err(error!(SwitchboardError::StaleFeed).with_values((staleness, max_staleness)));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_slots(
+        slot: u64,
+        round_open_slot: u64,
+        max_staleness: u64,
+    ) -> anchor_lang::Result<()> {
+        let staleness = slot.saturating_sub(round_open_slot);
+        if staleness > max_staleness {
+            msg!("Feed has not been updated in {} slots!", staleness);
+            return Err(error!(SwitchboardError::StaleFeed).with_values((staleness, max_staleness)));
+        }
+        Ok(())
+    }
+}
+
 // #[zero_copy(unsafe)]
 #[account(zero_copy(unsafe))]
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(PartialEq, Debug)]
 pub struct AggregatorAccountData {
     /// Name of the aggregator to store on-chain.
@@ -142,7 +209,7 @@ impl TryInto<AggregatorAccountData> for Option<Vec<u8>> {
     fn try_into(self) -> std::result::Result<AggregatorAccountData, Self::Error> {
         if let Some(data) = self {
             bytemuck::try_from_bytes(&data)
-                .map(|&x| x)
+                .copied()
                 .map_err(|_| SwitchboardError::AccountDeserializationError)
         } else {
             Err(SwitchboardError::AccountDeserializationError)
@@ -171,16 +238,11 @@ impl AggregatorAccountData {
     pub fn new<'info>(
         switchboard_feed: &'info AccountInfo<'info>,
     ) -> anchor_lang::Result<Ref<'info, AggregatorAccountData>> {
-        let data = switchboard_feed.try_borrow_data()?;
-        if data.len() < AggregatorAccountData::discriminator().len() {
-            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
-        }
-
-        let mut disc_bytes = [0u8; 8];
-        disc_bytes.copy_from_slice(&data[..8]);
-        if disc_bytes != AggregatorAccountData::discriminator() {
-            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        if *switchboard_feed.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
         }
+        let data = switchboard_feed.try_borrow_data()?;
+        decode::account_data(&data, &layout::AGGREGATOR_ACCOUNT_DATA).map_err(decode_error)?;
 
         Ok(Ref::map(data, |data| {
             bytemuck::from_bytes(&data[8..std::mem::size_of::<AggregatorAccountData>() + 8])
@@ -201,15 +263,7 @@ impl AggregatorAccountData {
     /// let data_feed = AggregatorAccountData::new_from_bytes(feed_account_info.try_borrow_data()?)?;
     /// ```
     pub fn new_from_bytes(data: &[u8]) -> anchor_lang::Result<&AggregatorAccountData> {
-        if data.len() < AggregatorAccountData::discriminator().len() {
-            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
-        }
-
-        let mut disc_bytes = [0u8; 8];
-        disc_bytes.copy_from_slice(&data[..8]);
-        if disc_bytes != AggregatorAccountData::discriminator() {
-            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
-        }
+        decode::account_data(data, &layout::AGGREGATOR_ACCOUNT_DATA).map_err(decode_error)?;
 
         Ok(bytemuck::from_bytes(
             &data[8..std::mem::size_of::<AggregatorAccountData>() + 8],
@@ -239,6 +293,66 @@ impl AggregatorAccountData {
         Ok(self.latest_confirmed_round.result)
     }
 
+    /// Returns the latest on-chain result, additionally requiring feeds using sliding window
+    /// resolution to have at least `min_oracle_results` distinct oracles report within the last
+    /// max_staleness seconds. Round resolution feeds behave the same as [`Self::get_result`].
+    ///
+    /// The sliding result account must be the aggregator's PDA, see
+    /// [`SlidingResultAccountData::find_program_address`], so another feed's window cannot be
+    /// used to satisfy the quorum.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregator` - The aggregator account's pubkey
+    /// * `sliding_result_info` - The aggregator's sliding result account
+    /// * `unix_timestamp` - The current unix timestamp
+    /// * `max_staleness` - The maximum age in seconds of a response counted towards the quorum
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::AggregatorAccountData;
+    ///
+    /// let feed = AggregatorAccountData::new(feed_account_info)?;
+    /// let result = feed.get_result_strict(
+    ///     feed_account_info.key,
+    ///     sliding_result_account_info,
+    ///     Clock::get()?.unix_timestamp,
+    ///     60,
+    /// )?;
+    /// ```
+    pub fn get_result_strict<'info>(
+        &self,
+        aggregator: &Pubkey,
+        sliding_result_info: &'info AccountInfo<'info>,
+        unix_timestamp: i64,
+        max_staleness: i64,
+    ) -> anchor_lang::Result<SwitchboardDecimal> {
+        if self.resolution_mode != AggregatorResolutionMode::ModeSlidingResolution {
+            return self.get_result();
+        }
+        let sliding_result = SlidingResultAccountData::new(sliding_result_info)?;
+        let bump = sliding_result.bump;
+        if crate::pda::create_sliding_result(aggregator, bump).ok()
+            != Some(*sliding_result_info.key)
+        {
+            return Err(error!(SwitchboardError::InvalidSlidingResultAccount));
+        }
+        let min_oracle_results = self.min_oracle_results;
+        let fresh_oracle_count =
+            sliding_result.fresh_oracle_count(unix_timestamp.saturating_sub(max_staleness));
+        if fresh_oracle_count < min_oracle_results {
+            msg!(
+                "Sliding window has {} fresh oracle responses, {} required",
+                fresh_oracle_count,
+                min_oracle_results
+            );
+            return Err(error!(SwitchboardError::InsufficientFreshSlidingResponses)
+                .with_values((fresh_oracle_count, min_oracle_results)));
+        }
+        Ok(self.latest_confirmed_round.result)
+    }
+
     /// Check whether the confidence interval exceeds a given threshold
     ///
     /// # Examples
@@ -247,7 +361,7 @@ impl AggregatorAccountData {
     /// use switchboard_solana::{AggregatorAccountData, SwitchboardDecimal};
     ///
     /// let feed = AggregatorAccountData::new(feed_account_info)?;
-    /// feed.check_confidence_interval(SwitchboardDecimal::from_f64(0.80))?;
+    /// feed.check_confidence_interval(SwitchboardDecimal::try_from_f64(0.80)?)?;
     /// ```
     pub fn check_confidence_interval(
         &self,
@@ -259,6 +373,68 @@ impl AggregatorAccountData {
         Ok(())
     }
 
+    /// Check whether the confidence interval, as a fraction of the absolute result, exceeds a given threshold
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{AggregatorAccountData, SwitchboardDecimal};
+    ///
+    /// let feed = AggregatorAccountData::new(feed_account_info)?;
+    /// // reject the round if the std deviation exceeds 25 bps of the result
+    /// feed.check_confidence_interval_relative(SwitchboardDecimal::new(25, 4))?;
+    /// ```
+    pub fn check_confidence_interval_relative(
+        &self,
+        max_ratio: SwitchboardDecimal,
+    ) -> anchor_lang::Result<()> {
+        let std_deviation: Decimal = self.latest_confirmed_round.std_deviation.try_into()?;
+        if std_deviation > self.relative_bound(max_ratio)? {
+            return Err(SwitchboardError::ConfidenceIntervalExceeded.into());
+        }
+        Ok(())
+    }
+
+    /// Check whether the spread between the min and max oracle response, as a fraction of the
+    /// absolute result, exceeds a given threshold
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{AggregatorAccountData, SwitchboardDecimal};
+    ///
+    /// let feed = AggregatorAccountData::new(feed_account_info)?;
+    /// // reject the round if the oracle responses are more than 1% apart
+    /// feed.check_spread_relative(SwitchboardDecimal::new(1, 2))?;
+    /// ```
+    pub fn check_spread_relative(&self, max_ratio: SwitchboardDecimal) -> anchor_lang::Result<()> {
+        let min_response: Decimal = self.latest_confirmed_round.min_response.try_into()?;
+        let max_response: Decimal = self.latest_confirmed_round.max_response.try_into()?;
+        if min_response > max_response {
+            return Err(SwitchboardError::AllowedVarianceExceeded.into());
+        }
+        let spread = max_response
+            .checked_sub(min_response)
+            .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+        if spread > self.relative_bound(max_ratio)? {
+            return Err(SwitchboardError::AllowedVarianceExceeded.into());
+        }
+        Ok(())
+    }
+
+    /// Returns the absolute latest confirmed result multiplied by the given ratio.
+    fn relative_bound(&self, max_ratio: SwitchboardDecimal) -> anchor_lang::Result<Decimal> {
+        let max_ratio: Decimal = max_ratio.try_into()?;
+        if max_ratio.is_sign_negative() {
+            return Err(SwitchboardError::InvalidFunctionInput.into());
+        }
+        let result: Decimal = self.latest_confirmed_round.result.try_into()?;
+        result
+            .abs()
+            .checked_mul(max_ratio)
+            .ok_or(error!(SwitchboardError::IntegerOverflowError))
+    }
+
     /// Check the variance (as a percentage difference from the max delivered
     /// oracle value) from all oracles.
     pub fn check_variance(&self, max_variance: Decimal) -> anchor_lang::Result<()> {
@@ -292,12 +468,66 @@ impl AggregatorAccountData {
         unix_timestamp: i64,
         max_staleness: i64,
     ) -> anchor_lang::Result<()> {
-        let staleness = unix_timestamp - self.latest_confirmed_round.round_open_timestamp;
-        if staleness > max_staleness {
-            msg!("Feed has not been updated in {} seconds!", staleness);
-            return Err(SwitchboardError::StaleFeed.into());
-        }
-        Ok(())
+        StalenessBound::check_seconds(
+            unix_timestamp,
+            self.latest_confirmed_round.round_open_timestamp,
+            max_staleness,
+        )
+    }
+
+    /// Check whether the feed has been updated in the last max_staleness slots
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::AggregatorAccountData;
+    ///
+    /// let feed = AggregatorAccountData::new(feed_account_info)?;
+    /// feed.check_staleness_slots(clock::Clock::get().unwrap().slot, 750)?;
+    /// ```
+    pub fn check_staleness_slots(&self, slot: u64, max_staleness: u64) -> anchor_lang::Result<()> {
+        StalenessBound::check_slots(
+            slot,
+            self.latest_confirmed_round.round_open_slot,
+            max_staleness,
+        )
+    }
+
+    /// Check whether the feed is within the given staleness bound of the provided clock
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{AggregatorAccountData, StalenessBound};
+    ///
+    /// let feed = AggregatorAccountData::new(feed_account_info)?;
+    /// let bound = StalenessBound::Either { seconds: 300, slots: 750 };
+    /// feed.check_staleness_bound(&clock::Clock::get()?, bound)?;
+    /// ```
+    pub fn check_staleness_bound(
+        &self,
+        clock: &Clock,
+        bound: StalenessBound,
+    ) -> anchor_lang::Result<()> {
+        bound.check(
+            clock,
+            self.latest_confirmed_round.round_open_timestamp,
+            self.latest_confirmed_round.round_open_slot,
+        )
+    }
+
+    /// Check whether the feed is within the given staleness bound of the Clock sysvar
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{AggregatorAccountData, StalenessBound};
+    ///
+    /// let feed = AggregatorAccountData::new(feed_account_info)?;
+    /// feed.check_staleness_now(StalenessBound::Slots(750))?;
+    /// ```
+    pub fn check_staleness_now(&self, bound: StalenessBound) -> anchor_lang::Result<()> {
+        self.check_staleness_bound(&Clock::get()?, bound)
     }
 
     pub fn is_expired(&self) -> anchor_lang::Result<bool> {
@@ -308,6 +538,243 @@ impl AggregatorAccountData {
     }
 }
 
+/// The maximum standard deviation a [`FeedPolicy`] will accept for the latest confirmed round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfidenceBound {
+    /// Maximum standard deviation in the same units as the feed result.
+    Absolute(SwitchboardDecimal),
+    /// Maximum standard deviation as a fraction of the absolute feed result (0.01 = 1%).
+    Relative(SwitchboardDecimal),
+}
+
+/// The outcome of a single [`FeedPolicy`] check.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FeedCheck {
+    /// The check was not configured on the policy.
+    #[default]
+    Skipped,
+    /// The feed satisfied the check.
+    Passed,
+    /// The feed violated the check.
+    Failed,
+}
+
+impl FeedCheck {
+    fn from_bool(passed: bool) -> Self {
+        if passed {
+            FeedCheck::Passed
+        } else {
+            FeedCheck::Failed
+        }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        *self == FeedCheck::Failed
+    }
+}
+
+/// The result of evaluating a [`FeedPolicy`] against an aggregator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeedPolicyReport {
+    /// The latest confirmed result of the aggregator.
+    pub result: SwitchboardDecimal,
+    /// Whether the round met the aggregator's own `min_oracle_results`, see
+    /// [`AggregatorAccountData::get_result`]. Always checked.
+    pub round: FeedCheck,
+    /// Whether the feed was updated within the max staleness.
+    pub staleness: FeedCheck,
+    /// Whether the round's standard deviation was within the confidence bound.
+    pub confidence_interval: FeedCheck,
+    /// Whether the round received the minimum number of successful oracle responses.
+    pub min_responses: FeedCheck,
+    /// Whether the spread between the min and max oracle response was within the max spread.
+    pub spread: FeedCheck,
+    /// Whether the result was within the allowed value band.
+    pub value_band: FeedCheck,
+}
+
+impl FeedPolicyReport {
+    /// Returns true if none of the configured checks failed.
+    pub fn is_valid(&self) -> bool {
+        self.error().is_none()
+    }
+
+    /// Returns the error for the first failed check, if any.
+    pub fn error(&self) -> Option<SwitchboardError> {
+        if self.round.is_failed() {
+            return Some(SwitchboardError::InvalidAggregatorRound);
+        }
+        if self.staleness.is_failed() {
+            return Some(SwitchboardError::StaleFeed);
+        }
+        if self.min_responses.is_failed() {
+            return Some(SwitchboardError::InvalidAggregatorRound);
+        }
+        if self.confidence_interval.is_failed() {
+            return Some(SwitchboardError::ConfidenceIntervalExceeded);
+        }
+        if self.spread.is_failed() {
+            return Some(SwitchboardError::AllowedVarianceExceeded);
+        }
+        if self.value_band.is_failed() {
+            return Some(SwitchboardError::FeedValueOutOfRange);
+        }
+        None
+    }
+}
+
+/// A reusable set of checks to validate an aggregator's latest confirmed round before
+/// consuming its result.
+///
+/// # Examples
+///
+/// ```ignore
+/// use switchboard_solana::{AggregatorAccountData, FeedPolicy, StalenessBound, SwitchboardDecimal};
+///
+/// let policy = FeedPolicy::new()
+///     .staleness_bound(StalenessBound::Either { seconds: 300, slots: 750 })
+///     .max_relative_std_deviation(SwitchboardDecimal::new(1, 2))
+///     .min_responses(3);
+///
+/// let feed = AggregatorAccountData::new(feed_account_info)?;
+/// let (price, _report) = policy.validate(&feed, &Clock::get()?)?;
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeedPolicy {
+    /// Maximum age of the latest confirmed round.
+    pub max_staleness: Option<StalenessBound>,
+    /// Maximum standard deviation of the latest confirmed round.
+    pub max_std_deviation: Option<ConfidenceBound>,
+    /// Minimum number of successful oracle responses in the latest confirmed round.
+    pub min_responses: Option<u32>,
+    /// Maximum difference between the max and min oracle response of the latest confirmed round.
+    pub max_spread: Option<SwitchboardDecimal>,
+    /// Inclusive lower and upper bound for the feed result.
+    pub value_band: Option<(SwitchboardDecimal, SwitchboardDecimal)>,
+}
+
+impl FeedPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_staleness(mut self, max_staleness: i64) -> Self {
+        self.max_staleness = Some(StalenessBound::Seconds(max_staleness));
+        self
+    }
+
+    pub fn staleness_bound(mut self, bound: StalenessBound) -> Self {
+        self.max_staleness = Some(bound);
+        self
+    }
+
+    pub fn max_std_deviation(mut self, max_std_deviation: SwitchboardDecimal) -> Self {
+        self.max_std_deviation = Some(ConfidenceBound::Absolute(max_std_deviation));
+        self
+    }
+
+    pub fn max_relative_std_deviation(mut self, max_ratio: SwitchboardDecimal) -> Self {
+        self.max_std_deviation = Some(ConfidenceBound::Relative(max_ratio));
+        self
+    }
+
+    pub fn min_responses(mut self, min_responses: u32) -> Self {
+        self.min_responses = Some(min_responses);
+        self
+    }
+
+    pub fn max_spread(mut self, max_spread: SwitchboardDecimal) -> Self {
+        self.max_spread = Some(max_spread);
+        self
+    }
+
+    pub fn value_band(mut self, min: SwitchboardDecimal, max: SwitchboardDecimal) -> Self {
+        self.value_band = Some((min, max));
+        self
+    }
+
+    /// Run every configured check against the aggregator and report which ones passed.
+    ///
+    /// Returns an error if the round's values cannot be converted or the policy is
+    /// misconfigured, but not when a check fails.
+    pub fn evaluate(
+        &self,
+        feed: &AggregatorAccountData,
+        clock: &Clock,
+    ) -> anchor_lang::Result<FeedPolicyReport> {
+        let round = &feed.latest_confirmed_round;
+        let result = round.result;
+        let value: Decimal = result.try_into()?;
+
+        let mut report = FeedPolicyReport {
+            result,
+            round: FeedCheck::from_bool(feed.get_result().is_ok()),
+            ..Default::default()
+        };
+
+        if let Some(bound) = self.max_staleness {
+            report.staleness = FeedCheck::from_bool(!bound.is_stale(
+                clock,
+                round.round_open_timestamp,
+                round.round_open_slot,
+            ));
+        }
+
+        if let Some(min_responses) = self.min_responses {
+            report.min_responses = FeedCheck::from_bool(round.num_success >= min_responses);
+        }
+
+        if let Some(bound) = self.max_std_deviation {
+            let std_deviation: Decimal = round.std_deviation.try_into()?;
+            let max_std_deviation: Decimal = match bound {
+                ConfidenceBound::Absolute(max) => max.try_into()?,
+                ConfidenceBound::Relative(max_ratio) => feed.relative_bound(max_ratio)?,
+            };
+            report.confidence_interval = FeedCheck::from_bool(std_deviation <= max_std_deviation);
+        }
+
+        if let Some(max_spread) = self.max_spread {
+            let max_spread: Decimal = max_spread.try_into()?;
+            let min_response: Decimal = round.min_response.try_into()?;
+            let max_response: Decimal = round.max_response.try_into()?;
+            // an inverted range is reported as failed, see check_spread_relative
+            report.spread = FeedCheck::from_bool(
+                min_response <= max_response
+                    && max_response
+                        .checked_sub(min_response)
+                        .ok_or(error!(SwitchboardError::IntegerOverflowError))?
+                        <= max_spread,
+            );
+        }
+
+        if let Some((min, max)) = self.value_band {
+            let min: Decimal = min.try_into()?;
+            let max: Decimal = max.try_into()?;
+            if min > max {
+                return Err(error!(SwitchboardError::InvalidFunctionInput));
+            }
+            report.value_band = FeedCheck::from_bool(value >= min && value <= max);
+        }
+
+        Ok(report)
+    }
+
+    /// Run every configured check against the aggregator and return its result if all of
+    /// them passed, along with the report. Errors with the first failed check otherwise.
+    pub fn validate(
+        &self,
+        feed: &AggregatorAccountData,
+        clock: &Clock,
+    ) -> anchor_lang::Result<(SwitchboardDecimal, FeedPolicyReport)> {
+        let report = self.evaluate(feed, clock)?;
+        if let Some(err) = report.error() {
+            msg!("Switchboard feed failed policy validation: {:?}", report);
+            return Err(err.into());
+        }
+        Ok((report.result, report))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -326,7 +793,7 @@ mod tests {
         AggregatorRound {
             num_success,
             num_error,
-            result: SwitchboardDecimal::from_f64(value),
+            result: SwitchboardDecimal::try_from_f64(value).unwrap(),
             ..Default::default()
         }
     }
@@ -362,4 +829,443 @@ mod tests {
             "Aggregator is not currently populated with a valid round."
         );
     }
+
+    fn create_clock(unix_timestamp: i64) -> Clock {
+        Clock {
+            slot: 5_000,
+            unix_timestamp,
+            ..Default::default()
+        }
+    }
+
+    fn create_policy_round() -> AggregatorRound {
+        AggregatorRound {
+            num_success: 12,
+            round_open_timestamp: 1_000,
+            result: SwitchboardDecimal::new(10_000, 2),
+            std_deviation: SwitchboardDecimal::new(50, 2),
+            min_response: SwitchboardDecimal::new(9_900, 2),
+            max_response: SwitchboardDecimal::new(10_100, 2),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_feed_policy_passes() {
+        let aggregator = create_aggregator(create_policy_round());
+        let policy = FeedPolicy::new()
+            .max_staleness(60)
+            .max_relative_std_deviation(SwitchboardDecimal::new(1, 2))
+            .min_responses(12)
+            .max_spread(SwitchboardDecimal::new(2, 0))
+            .value_band(
+                SwitchboardDecimal::new(90, 0),
+                SwitchboardDecimal::new(110, 0),
+            );
+
+        let (result, report) = policy.validate(&aggregator, &create_clock(1_060)).unwrap();
+        assert_eq!(result, SwitchboardDecimal::new(10_000, 2));
+        assert!(report.is_valid());
+        assert_eq!(report.staleness, FeedCheck::Passed);
+        assert_eq!(report.confidence_interval, FeedCheck::Passed);
+        assert_eq!(report.min_responses, FeedCheck::Passed);
+        assert_eq!(report.spread, FeedCheck::Passed);
+        assert_eq!(report.value_band, FeedCheck::Passed);
+    }
+
+    #[test]
+    fn test_feed_policy_skips_unconfigured_checks() {
+        let aggregator = create_aggregator(create_policy_round());
+        let report = FeedPolicy::new()
+            .max_staleness(60)
+            .evaluate(&aggregator, &create_clock(1_000))
+            .unwrap();
+        assert_eq!(report.staleness, FeedCheck::Passed);
+        assert_eq!(report.confidence_interval, FeedCheck::Skipped);
+        assert_eq!(report.value_band, FeedCheck::Skipped);
+    }
+
+    #[test]
+    fn test_feed_policy_reports_failures() {
+        let aggregator = create_aggregator(create_policy_round());
+        let policy = FeedPolicy::new()
+            .max_staleness(60)
+            .max_std_deviation(SwitchboardDecimal::new(10, 2))
+            .max_spread(SwitchboardDecimal::new(1, 0))
+            .value_band(
+                SwitchboardDecimal::new(101, 0),
+                SwitchboardDecimal::new(110, 0),
+            );
+
+        let report = policy.evaluate(&aggregator, &create_clock(1_061)).unwrap();
+        assert_eq!(report.staleness, FeedCheck::Failed);
+        assert_eq!(report.confidence_interval, FeedCheck::Failed);
+        assert_eq!(report.spread, FeedCheck::Failed);
+        assert_eq!(report.value_band, FeedCheck::Failed);
+        assert_eq!(report.error(), Some(SwitchboardError::StaleFeed));
+
+        let err = policy
+            .validate(&aggregator, &create_clock(1_000))
+            .unwrap_err();
+        assert_eq!(err, SwitchboardError::ConfidenceIntervalExceeded.into());
+    }
+
+    #[test]
+    fn test_feed_policy_rejects_invalid_band() {
+        let aggregator = create_aggregator(create_policy_round());
+        let policy = FeedPolicy::new().value_band(
+            SwitchboardDecimal::new(110, 0),
+            SwitchboardDecimal::new(90, 0),
+        );
+        assert!(policy.evaluate(&aggregator, &create_clock(1_000)).is_err());
+    }
+
+    #[test]
+    fn test_feed_policy_reports_invalid_round() {
+        let mut round = create_policy_round();
+        round.num_success = 9;
+        let aggregator = create_aggregator(round);
+        let report = FeedPolicy::new()
+            .max_staleness(60)
+            .evaluate(&aggregator, &create_clock(1_000))
+            .unwrap();
+        assert_eq!(report.round, FeedCheck::Failed);
+        assert_eq!(report.staleness, FeedCheck::Passed);
+        assert_eq!(report.result, SwitchboardDecimal::new(10_000, 2));
+        assert_eq!(
+            report.error(),
+            Some(SwitchboardError::InvalidAggregatorRound)
+        );
+    }
+
+    #[test]
+    fn test_feed_policy_fails_inverted_spread() {
+        let mut round = create_policy_round();
+        std::mem::swap(&mut round.min_response, &mut round.max_response);
+        let aggregator = create_aggregator(round);
+        let report = FeedPolicy::new()
+            .max_spread(SwitchboardDecimal::new(1_000, 0))
+            .evaluate(&aggregator, &create_clock(1_000))
+            .unwrap();
+        assert_eq!(report.spread, FeedCheck::Failed);
+    }
+
+    #[test]
+    fn test_feed_policy_staleness_bound() {
+        let mut round = create_policy_round();
+        round.round_open_slot = 4_900;
+        let aggregator = create_aggregator(round);
+        let policy = FeedPolicy::new().staleness_bound(StalenessBound::Slots(100));
+        let report = policy.evaluate(&aggregator, &create_clock(1_000)).unwrap();
+        assert_eq!(report.staleness, FeedCheck::Passed);
+
+        let mut clock = create_clock(1_000);
+        clock.slot = 5_001;
+        let report = policy.evaluate(&aggregator, &clock).unwrap();
+        assert_eq!(report.staleness, FeedCheck::Failed);
+    }
+
+    #[test]
+    fn test_relative_confidence_interval() {
+        let mut round = create_policy_round();
+        let aggregator = create_aggregator(round);
+        // std deviation of 0.50 on a result of 100.00 is 50 bps
+        assert!(aggregator
+            .check_confidence_interval_relative(SwitchboardDecimal::new(50, 4))
+            .is_ok());
+        assert_eq!(
+            aggregator
+                .check_confidence_interval_relative(SwitchboardDecimal::new(49, 4))
+                .unwrap_err(),
+            SwitchboardError::ConfidenceIntervalExceeded.into()
+        );
+
+        // negative results are compared by magnitude
+        round.result = SwitchboardDecimal::new(-10_000, 2);
+        let aggregator = create_aggregator(round);
+        assert!(aggregator
+            .check_confidence_interval_relative(SwitchboardDecimal::new(50, 4))
+            .is_ok());
+        assert!(aggregator
+            .check_confidence_interval_relative(SwitchboardDecimal::new(49, 4))
+            .is_err());
+    }
+
+    #[test]
+    fn test_relative_confidence_interval_zero_result() {
+        let mut round = create_policy_round();
+        round.result = SwitchboardDecimal::new(0, 0);
+        let aggregator = create_aggregator(round);
+        assert!(aggregator
+            .check_confidence_interval_relative(SwitchboardDecimal::new(1, 0))
+            .is_err());
+
+        round.std_deviation = SwitchboardDecimal::new(0, 0);
+        let aggregator = create_aggregator(round);
+        assert!(aggregator
+            .check_confidence_interval_relative(SwitchboardDecimal::new(0, 0))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_relative_confidence_interval_invalid_input() {
+        let mut round = create_policy_round();
+        let aggregator = create_aggregator(round);
+        assert_eq!(
+            aggregator
+                .check_confidence_interval_relative(SwitchboardDecimal::new(-1, 2))
+                .unwrap_err(),
+            SwitchboardError::InvalidFunctionInput.into()
+        );
+
+        // overflows the 96-bit decimal instead of panicking
+        round.result = SwitchboardDecimal::new(79_228_162_514_264_337_593_543_950_335, 0);
+        let aggregator = create_aggregator(round);
+        assert_eq!(
+            aggregator
+                .check_confidence_interval_relative(SwitchboardDecimal::new(2, 0))
+                .unwrap_err(),
+            SwitchboardError::IntegerOverflowError.into()
+        );
+    }
+
+    #[test]
+    fn test_relative_spread() {
+        let mut round = create_policy_round();
+        let aggregator = create_aggregator(round);
+        // spread of 2.00 on a result of 100.00 is 2%
+        assert!(aggregator
+            .check_spread_relative(SwitchboardDecimal::new(2, 2))
+            .is_ok());
+        assert_eq!(
+            aggregator
+                .check_spread_relative(SwitchboardDecimal::new(199, 4))
+                .unwrap_err(),
+            SwitchboardError::AllowedVarianceExceeded.into()
+        );
+
+        round.result = SwitchboardDecimal::new(-10_000, 2);
+        round.min_response = SwitchboardDecimal::new(-10_100, 2);
+        round.max_response = SwitchboardDecimal::new(-9_900, 2);
+        let aggregator = create_aggregator(round);
+        assert!(aggregator
+            .check_spread_relative(SwitchboardDecimal::new(2, 2))
+            .is_ok());
+
+        round.result = SwitchboardDecimal::new(0, 0);
+        let aggregator = create_aggregator(round);
+        assert!(aggregator
+            .check_spread_relative(SwitchboardDecimal::new(2, 2))
+            .is_err());
+    }
+
+    #[test]
+    fn test_staleness_bounds() {
+        let round = AggregatorRound {
+            round_open_timestamp: 1_000,
+            round_open_slot: 5_000,
+            ..Default::default()
+        };
+        let aggregator = create_aggregator(round);
+        let clock = Clock {
+            slot: 5_100,
+            unix_timestamp: 1_030,
+            ..Default::default()
+        };
+
+        assert!(aggregator.check_staleness(1_030, 30).is_ok());
+        assert!(aggregator.check_staleness(1_031, 30).is_err());
+        assert!(aggregator.check_staleness_slots(5_100, 100).is_ok());
+        assert!(aggregator.check_staleness_slots(5_101, 100).is_err());
+
+        assert!(aggregator
+            .check_staleness_bound(&clock, StalenessBound::Seconds(30))
+            .is_ok());
+        assert!(aggregator
+            .check_staleness_bound(&clock, StalenessBound::Slots(99))
+            .is_err());
+        assert!(aggregator
+            .check_staleness_bound(
+                &clock,
+                StalenessBound::Either {
+                    seconds: 30,
+                    slots: 100
+                }
+            )
+            .is_ok());
+        assert!(aggregator
+            .check_staleness_bound(
+                &clock,
+                StalenessBound::Either {
+                    seconds: 29,
+                    slots: 100
+                }
+            )
+            .is_err());
+        assert!(aggregator
+            .check_staleness_bound(
+                &clock,
+                StalenessBound::Either {
+                    seconds: 30,
+                    slots: 99
+                }
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_staleness_reports_age() {
+        let round = AggregatorRound {
+            round_open_slot: 5_000,
+            ..Default::default()
+        };
+        let aggregator = create_aggregator(round);
+
+        match aggregator.check_staleness_slots(5_250, 150).unwrap_err() {
+            anchor_lang::error::Error::AnchorError(err) => {
+                assert_eq!(
+                    err.error_code_number,
+                    anchor_lang::error::ERROR_CODE_OFFSET + SwitchboardError::StaleFeed as u32
+                );
+                match err.compared_values {
+                    Some(anchor_lang::error::ComparedValues::Values((
+                        staleness,
+                        max_staleness,
+                    ))) => {
+                        assert_eq!(staleness, "250");
+                        assert_eq!(max_staleness, "150");
+                    }
+                    values => panic!("unexpected compared values {:?}", values),
+                }
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    fn sliding_result_data(aggregator: &Pubkey, timestamps: &[i64]) -> (Pubkey, Vec<u8>) {
+        let (key, bump) = SlidingResultAccountData::find_program_address(aggregator);
+        let mut sliding_result = SlidingResultAccountData {
+            bump,
+            ..Default::default()
+        };
+        for (i, timestamp) in timestamps.iter().enumerate() {
+            sliding_result.data[i] = SlidingWindowElement {
+                oracle_key: Pubkey::new_from_array([i as u8 + 1; 32]),
+                value: SwitchboardDecimal::new(100, 0),
+                slot: 0,
+                timestamp: *timestamp,
+            };
+        }
+        let mut data = SlidingResultAccountData::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&sliding_result));
+        (key, data)
+    }
+
+    #[test]
+    fn test_sliding_result_quorum() {
+        let mut aggregator = create_aggregator(create_round(100.0, 0, 0));
+        aggregator.resolution_mode = AggregatorResolutionMode::ModeSlidingResolution;
+        aggregator.min_oracle_results = 2;
+
+        let aggregator_key = Pubkey::new_unique();
+        let (sliding_result_key, mut data) = sliding_result_data(&aggregator_key, &[100, 150, 160]);
+        let mut lamports = 0;
+        let sliding_result_info = AccountInfo::new(
+            &sliding_result_key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &SWITCHBOARD_PROGRAM_ID,
+            false,
+            0,
+        );
+
+        // the unchecked path returns the sliding result regardless of quorum
+        assert!(aggregator.get_result().is_ok());
+
+        assert_eq!(
+            aggregator
+                .get_result_strict(&aggregator_key, &sliding_result_info, 170, 20)
+                .unwrap(),
+            SwitchboardDecimal::new(100, 0)
+        );
+        assert_eq!(
+            aggregator
+                .get_result_strict(&aggregator_key, &sliding_result_info, 170, 15)
+                .unwrap_err(),
+            SwitchboardError::InsufficientFreshSlidingResponses.into()
+        );
+    }
+
+    #[test]
+    fn test_sliding_result_must_belong_to_aggregator() {
+        let mut aggregator = create_aggregator(create_round(100.0, 0, 0));
+        aggregator.resolution_mode = AggregatorResolutionMode::ModeSlidingResolution;
+        aggregator.min_oracle_results = 2;
+
+        // another feed's well populated window must not satisfy the quorum
+        let aggregator_key = Pubkey::new_unique();
+        let other_aggregator_key = Pubkey::new_unique();
+        let (other_key, mut data) =
+            sliding_result_data(&other_aggregator_key, &[160, 160, 160, 160]);
+        let mut lamports = 0;
+        let other_info = AccountInfo::new(
+            &other_key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &SWITCHBOARD_PROGRAM_ID,
+            false,
+            0,
+        );
+        assert_eq!(
+            aggregator
+                .get_result_strict(&aggregator_key, &other_info, 170, 20)
+                .unwrap_err(),
+            SwitchboardError::InvalidSlidingResultAccount.into()
+        );
+        assert!(aggregator
+            .get_result_strict(&other_aggregator_key, &other_info, 170, 20)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_strict_result_round_resolution() {
+        let aggregator = create_aggregator(create_round(100.0, 5, 0));
+        let aggregator_key = Pubkey::new_unique();
+        let (sliding_result_key, mut data) = sliding_result_data(&aggregator_key, &[]);
+        let mut lamports = 0;
+        let sliding_result_info = AccountInfo::new(
+            &sliding_result_key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &SWITCHBOARD_PROGRAM_ID,
+            false,
+            0,
+        );
+        assert_eq!(
+            aggregator
+                .get_result_strict(&aggregator_key, &sliding_result_info, 0, 0)
+                .unwrap_err(),
+            SwitchboardError::InvalidAggregatorRound.into()
+        );
+    }
+
+    #[test]
+    fn test_new_from_bytes_rejects_short_data() {
+        let aggregator = create_aggregator(create_round(100.0, 30, 0));
+        let mut data = AggregatorAccountData::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&aggregator));
+
+        assert!(AggregatorAccountData::new_from_bytes(&data).is_ok());
+        assert!(AggregatorAccountData::new_from_bytes(&data[..4]).is_err());
+        assert_eq!(
+            AggregatorAccountData::new_from_bytes(&data[..data.len() - 1]).unwrap_err(),
+            SwitchboardError::AccountDeserializationError.into()
+        );
+    }
 }
@@ -1,5 +1,5 @@
 #[zero_copy(unsafe)]
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Default, Debug, PartialEq, Eq)]
 pub struct Hash {
     /// The bytes used to derive the hash.
@@ -7,7 +7,7 @@ pub struct Hash {
 }
 
 #[zero_copy(unsafe)]
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Default, PartialEq, Eq)]
 pub struct AggregatorRound {
     /// Maintains the number of successful responses received from nodes.
@@ -50,7 +50,7 @@ pub enum AggregatorResolutionMode {
 
 // #[zero_copy(unsafe)]
 #[account(zero_copy(unsafe))]
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(PartialEq)]
 pub struct AggregatorAccountData {
     /// Name of the aggregator to store on-chain.
@@ -89,11 +89,65 @@ impl BufferRelayerAccountData {
         unix_timestamp: i64,
         max_staleness: i64,
     ) -> anchor_lang::Result<()> {
-        let staleness = unix_timestamp - self.latest_confirmed_round.round_open_timestamp;
-        if staleness > max_staleness {
-            msg!("Feed has not been updated in {} seconds!", staleness);
-            return Err(SwitchboardError::StaleFeed.into());
-        }
-        Ok(())
+        StalenessBound::check_seconds(
+            unix_timestamp,
+            self.latest_confirmed_round.round_open_timestamp,
+            max_staleness,
+        )
+    }
+
+    /// Check whether the buffer relayer has been updated in the last max_staleness slots
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::BufferRelayerAccountData;
+    ///
+    /// let buffer = BufferRelayerAccountData::new(buffer_account_info)?;
+    /// buffer.check_staleness_slots(clock::Clock::get().unwrap().slot, 750)?;
+    /// ```
+    pub fn check_staleness_slots(&self, slot: u64, max_staleness: u64) -> anchor_lang::Result<()> {
+        StalenessBound::check_slots(
+            slot,
+            self.latest_confirmed_round.round_open_slot,
+            max_staleness,
+        )
+    }
+
+    /// Check whether the buffer relayer is within the given staleness bound of the provided clock
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{BufferRelayerAccountData, StalenessBound};
+    ///
+    /// let buffer = BufferRelayerAccountData::new(buffer_account_info)?;
+    /// let bound = StalenessBound::Either { seconds: 300, slots: 750 };
+    /// buffer.check_staleness_bound(&clock::Clock::get()?, bound)?;
+    /// ```
+    pub fn check_staleness_bound(
+        &self,
+        clock: &Clock,
+        bound: StalenessBound,
+    ) -> anchor_lang::Result<()> {
+        bound.check(
+            clock,
+            self.latest_confirmed_round.round_open_timestamp,
+            self.latest_confirmed_round.round_open_slot,
+        )
+    }
+
+    /// Check whether the buffer relayer is within the given staleness bound of the Clock sysvar
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{BufferRelayerAccountData, StalenessBound};
+    ///
+    /// let buffer = BufferRelayerAccountData::new(buffer_account_info)?;
+    /// buffer.check_staleness_now(StalenessBound::Slots(750))?;
+    /// ```
+    pub fn check_staleness_now(&self, bound: StalenessBound) -> anchor_lang::Result<()> {
+        self.check_staleness_bound(&Clock::get()?, bound)
     }
 }
@@ -1,9 +1,11 @@
 use crate::prelude::*;
-use bytemuck::{Pod, Zeroable};
+use bytemuck::{try_cast_slice, Pod, Zeroable};
+use std::cell::Ref;
+use switchboard_core::{decode, layout, DecodeError};
 
 #[zero_copy(unsafe)]
-#[derive(Default)]
-#[repr(packed)]
+#[derive(Default, Debug)]
+#[repr(C, packed)]
 pub struct CrankRow {
     /// The PublicKey of the AggregatorAccountData.
     pub pubkey: Pubkey,
@@ -14,7 +16,7 @@ unsafe impl Pod for CrankRow {}
 unsafe impl Zeroable for CrankRow {}
 
 #[account(zero_copy(unsafe))]
-#[repr(packed)]
+#[repr(C, packed)]
 pub struct CrankAccountData {
     /// Name of the crank to store on-chain.
     pub name: [u8; 32],
@@ -34,8 +36,236 @@ pub struct CrankAccountData {
     pub data_buffer: Pubkey,
 }
 
+impl Default for CrankAccountData {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
 impl CrankAccountData {
     pub fn size() -> usize {
         8 + std::mem::size_of::<CrankAccountData>()
     }
+
+    /// Returns the deserialized Switchboard Crank account
+    ///
+    /// # Arguments
+    ///
+    /// * `account_info` - A Solana AccountInfo referencing an existing Switchboard Crank
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::CrankAccountData;
+    ///
+    /// let crank = CrankAccountData::new(crank_account_info)?;
+    /// ```
+    pub fn new<'info>(
+        account_info: &'info AccountInfo<'info>,
+    ) -> anchor_lang::Result<Ref<'info, Self>> {
+        if *account_info.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
+        }
+        let data = account_info.try_borrow_data()?;
+        decode::account_data(&data, &layout::CRANK_ACCOUNT_DATA).map_err(decode_error)?;
+
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..std::mem::size_of::<CrankAccountData>() + 8])
+        }))
+    }
+
+    /// Returns the deserialized Switchboard Crank account
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A Solana AccountInfo's data buffer
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::CrankAccountData;
+    ///
+    /// let crank = CrankAccountData::new_from_bytes(crank_account_info.try_borrow_data()?)?;
+    /// ```
+    pub fn new_from_bytes(data: &[u8]) -> anchor_lang::Result<&CrankAccountData> {
+        decode::account_data(data, &layout::CRANK_ACCOUNT_DATA).map_err(decode_error)?;
+
+        Ok(bytemuck::from_bytes(
+            &data[8..std::mem::size_of::<CrankAccountData>() + 8],
+        ))
+    }
+
+    /// Returns the rows on the crank, the first `pq_size` entries of its data buffer. The rows
+    /// are stored as a binary heap, use [`CrankAccountData::sorted_rows`] to order them.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer_info` - A Solana AccountInfo referencing the crank's data buffer
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::CrankAccountData;
+    ///
+    /// let crank = CrankAccountData::new(crank_account_info)?;
+    /// let rows = crank.load_rows(data_buffer_account_info)?;
+    /// ```
+    pub fn load_rows<'info>(
+        &self,
+        buffer_info: &'info AccountInfo<'info>,
+    ) -> anchor_lang::Result<Ref<'info, [CrankRow]>> {
+        if *buffer_info.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
+        }
+        if *buffer_info.key != self.data_buffer {
+            return Err(error!(SwitchboardError::InvalidCrankDataBuffer));
+        }
+        let data = buffer_info.try_borrow_data()?;
+        let rows_len = std::mem::size_of_val(self.rows_from_bytes(&data)?);
+
+        Ref::filter_map(data, |data| try_cast_slice(&data[8..8 + rows_len]).ok())
+            .map_err(|_| error!(SwitchboardError::AccountDeserializationError))
+    }
+
+    /// Returns the rows on the crank from the raw data of its data buffer. Useful for turning
+    /// cranks off-chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The crank's data buffer account data
+    pub fn rows_from_bytes<'a>(&self, data: &'a [u8]) -> anchor_lang::Result<&'a [CrankRow]> {
+        if self.pq_size > self.max_rows {
+            return Err(error!(SwitchboardError::AccountDeserializationError));
+        }
+        let rows = decode::crank_buffer(data, self.pq_size as usize).map_err(|err| match err {
+            DecodeError::DiscriminatorMismatch => {
+                SwitchboardError::AccountDiscriminatorMismatch.into()
+            }
+            err => decode_error(err),
+        })?;
+
+        try_cast_slice(rows).map_err(|_| error!(SwitchboardError::AccountDeserializationError))
+    }
+
+    /// Returns the rows ordered by their next available update time, the order the crank pops
+    /// them in. The crank's binary heap pops rows with the same update time in an unspecified
+    /// order, here they are ordered by aggregator pubkey so the result is deterministic.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The rows on the crank
+    pub fn sorted_rows(&self, rows: &[CrankRow]) -> Vec<CrankRow> {
+        let mut rows = rows.to_vec();
+        rows.sort_by_key(|row| ({ row.next_timestamp }, { row.pubkey }));
+        rows
+    }
+
+    /// Returns the rows that can be popped at `now`, ordered as in [`Self::sorted_rows`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The rows on the crank
+    /// * `now` - The current unix timestamp
+    pub fn ready_at(&self, rows: &[CrankRow], now: i64) -> Vec<CrankRow> {
+        let mut rows = self.sorted_rows(rows);
+        rows.retain(|row| row.next_timestamp <= now);
+        rows
+    }
+
+    /// Returns the position of the aggregator in [`Self::sorted_rows`], or None if the
+    /// aggregator is not on the crank. Rows sharing the aggregator's update time may be popped
+    /// before or after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The rows on the crank
+    /// * `aggregator` - The aggregator to find
+    pub fn position_of(&self, rows: &[CrankRow], aggregator: &Pubkey) -> Option<usize> {
+        self.sorted_rows(rows)
+            .iter()
+            .position(|row| row.pubkey == *aggregator)
+    }
+
+    /// Returns true if the crank has room for another aggregator.
+    pub fn has_capacity(&self) -> bool {
+        self.pq_size < self.max_rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BUFFER_DISCRIMINATOR;
+
+    fn row(i: u8, next_timestamp: i64) -> CrankRow {
+        CrankRow {
+            pubkey: Pubkey::new_from_array([i; 32]),
+            next_timestamp,
+        }
+    }
+
+    fn buffer_data(rows: &[CrankRow], max_rows: usize) -> Vec<u8> {
+        let mut data = BUFFER_DISCRIMINATOR.to_vec();
+        for row in rows {
+            data.extend_from_slice(bytemuck::bytes_of(row));
+        }
+        data.resize(8 + max_rows * layout::CRANK_ROW.size, 0);
+        data
+    }
+
+    #[test]
+    fn test_rows_from_bytes() {
+        let mut crank = CrankAccountData {
+            pq_size: 2,
+            max_rows: 4,
+            ..Default::default()
+        };
+        let data = buffer_data(&[row(1, 100), row(2, 200), row(3, 300)], 4);
+        let rows = crank.rows_from_bytes(&data).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert!(crank.has_capacity());
+
+        crank.pq_size = 5;
+        assert_eq!(
+            crank.rows_from_bytes(&data).unwrap_err(),
+            SwitchboardError::AccountDeserializationError.into()
+        );
+        crank.max_rows = 5;
+        assert!(crank.rows_from_bytes(&data).is_err());
+        crank.pq_size = 2;
+        assert_eq!(
+            crank.rows_from_bytes(&data[8..]).unwrap_err(),
+            SwitchboardError::AccountDiscriminatorMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_scheduling() {
+        let crank = CrankAccountData {
+            pq_size: 4,
+            max_rows: 4,
+            ..Default::default()
+        };
+        // heap order, not sorted
+        let data = buffer_data(&[row(1, 100), row(2, 300), row(3, 200), row(4, 100)], 4);
+        let rows = crank.rows_from_bytes(&data).unwrap();
+
+        let sorted: Vec<u8> = crank
+            .sorted_rows(rows)
+            .iter()
+            .map(|row| row.pubkey.to_bytes()[0])
+            .collect();
+        assert_eq!(sorted, vec![1, 4, 3, 2]);
+
+        assert!(crank.ready_at(rows, 99).is_empty());
+        assert_eq!(crank.ready_at(rows, 200).len(), 3);
+
+        assert_eq!(
+            crank.position_of(rows, &Pubkey::new_from_array([3; 32])),
+            Some(2)
+        );
+        assert_eq!(crank.position_of(rows, &Pubkey::default()), None);
+
+        assert!(!crank.has_capacity());
+    }
 }
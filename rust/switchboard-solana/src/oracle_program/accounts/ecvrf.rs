@@ -0,0 +1,762 @@
+#![allow(non_snake_case)]
+use crate::prelude::*;
+use bytemuck::{Pod, Zeroable};
+pub use switchboard_core::types::VrfRound;
+
+#[zero_copy(unsafe)]
+#[derive(Default)]
+#[repr(C, packed)]
+pub struct AccountMetaZC {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[zero_copy(unsafe)]
+#[derive(Default, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+#[repr(C)]
+pub struct AccountMetaBorsh {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+impl From<AccountMeta> for AccountMetaBorsh {
+    fn from(value: AccountMeta) -> Self {
+        AccountMetaBorsh {
+            pubkey: value.pubkey,
+            is_signer: value.is_signer,
+            is_writable: value.is_writable,
+        }
+    }
+}
+impl From<AccountMetaBorsh> for AccountMeta {
+    fn from(value: AccountMetaBorsh) -> Self {
+        AccountMeta {
+            pubkey: value.pubkey,
+            is_signer: value.is_signer,
+            is_writable: value.is_writable,
+        }
+    }
+}
+impl From<AccountMetaBorsh> for AccountMetaZC {
+    fn from(value: AccountMetaBorsh) -> Self {
+        AccountMetaZC {
+            pubkey: value.pubkey,
+            is_signer: value.is_signer,
+            is_writable: value.is_writable,
+        }
+    }
+}
+impl From<AccountMetaZC> for AccountMetaBorsh {
+    fn from(value: AccountMetaZC) -> Self {
+        AccountMetaBorsh {
+            pubkey: value.pubkey,
+            is_signer: value.is_signer,
+            is_writable: value.is_writable,
+        }
+    }
+}
+
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+pub struct CallbackZC {
+    /// The program ID of the callback program being invoked.
+    pub program_id: Pubkey,
+    /// The accounts being used in the callback instruction.
+    pub accounts: [AccountMetaZC; 32],
+    /// The number of accounts used in the callback
+    pub accounts_len: u32,
+    /// The serialized instruction data.
+    pub ix_data: [u8; 1024],
+    /// The number of serialized bytes in the instruction data.
+    pub ix_data_len: u32,
+}
+impl Default for CallbackZC {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl CallbackZC {
+    /// The maximum number of accounts a callback can reference.
+    pub const MAX_ACCOUNTS: usize = 32;
+    /// The maximum number of bytes of serialized instruction data a callback can hold.
+    pub const MAX_IX_DATA_LEN: usize = 1024;
+}
+
+impl TryFrom<Callback> for CallbackZC {
+    type Error = anchor_lang::error::Error;
+
+    fn try_from(value: Callback) -> anchor_lang::Result<Self> {
+        value.validate()?;
+
+        let mut callback = CallbackZC {
+            program_id: value.program_id,
+            accounts_len: value.accounts.len() as u32,
+            ix_data_len: value.ix_data.len() as u32,
+            ..Default::default()
+        };
+        for (account, meta) in callback.accounts.iter_mut().zip(value.accounts) {
+            *account = meta.into();
+        }
+        callback.ix_data[..value.ix_data.len()].copy_from_slice(&value.ix_data);
+
+        Ok(callback)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct Callback {
+    /// The program ID of the callback program being invoked.
+    pub program_id: Pubkey,
+    /// The accounts being used in the callback instruction.
+    pub accounts: Vec<AccountMetaBorsh>,
+    /// The serialized instruction data.
+    pub ix_data: Vec<u8>,
+}
+
+impl Callback {
+    /// Builds a callback from an Anchor instruction's accounts and instruction data.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The program ID of the callback program being invoked.
+    /// * `accounts` - The accounts struct of the callback instruction, e.g. `my_program::accounts::UpdateResult`.
+    /// * `data` - The instruction data of the callback instruction, e.g. `my_program::instruction::UpdateResult`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::Callback;
+    ///
+    /// let callback = Callback::new(
+    ///     crate::ID,
+    ///     crate::accounts::UpdateResult {
+    ///         state: ctx.accounts.state.key(),
+    ///         vrf: ctx.accounts.vrf.key(),
+    ///     },
+    ///     crate::instruction::UpdateResult {},
+    /// )?;
+    /// ```
+    pub fn new(
+        program_id: Pubkey,
+        accounts: impl ToAccountMetas,
+        data: impl InstructionData,
+    ) -> anchor_lang::Result<Self> {
+        let callback = Callback {
+            program_id,
+            accounts: accounts
+                .to_account_metas(None)
+                .into_iter()
+                .map(AccountMetaBorsh::from)
+                .collect(),
+            ix_data: data.data(),
+        };
+        callback.validate()?;
+
+        Ok(callback)
+    }
+
+    /// Returns an error if the callback does not fit in a [`CallbackZC`].
+    pub fn validate(&self) -> anchor_lang::Result<()> {
+        if self.accounts.len() > CallbackZC::MAX_ACCOUNTS {
+            return Err(error!(SwitchboardError::CallbackTooManyAccounts));
+        }
+        if self.ix_data.len() > CallbackZC::MAX_IX_DATA_LEN {
+            return Err(error!(SwitchboardError::CallbackIxDataTooLarge));
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<CallbackZC> for Callback {
+    type Error = anchor_lang::error::Error;
+
+    /// Returns an error if the stored lengths exceed the fixed size buffers, as in a corrupt
+    /// account.
+    fn try_from(value: CallbackZC) -> anchor_lang::Result<Self> {
+        let accounts_len = value.accounts_len as usize;
+        if accounts_len > CallbackZC::MAX_ACCOUNTS {
+            return Err(error!(SwitchboardError::CallbackTooManyAccounts));
+        }
+        let ix_data_len = value.ix_data_len as usize;
+        if ix_data_len > CallbackZC::MAX_IX_DATA_LEN {
+            return Err(error!(SwitchboardError::CallbackIxDataTooLarge));
+        }
+
+        Ok(Callback {
+            program_id: value.program_id,
+            accounts: value.accounts[..accounts_len]
+                .iter()
+                .map(|account| AccountMetaBorsh::from(*account))
+                .collect(),
+            ix_data: value.ix_data[..ix_data_len].to_vec(),
+        })
+    }
+}
+
+#[derive(Copy, Clone, Default, Eq, PartialEq, Debug)]
+#[repr(u8)]
+pub enum VrfStatus {
+    /// VRF Account has not requested randomness yet.
+    #[default]
+    StatusNone,
+    /// VRF Account has requested randomness but has yet to receive an oracle response.
+    StatusRequesting,
+    /// VRF Account has received a VRF proof that has yet to be verified on-chain.
+    StatusVerifying,
+    /// VRF Account has successfully requested and verified randomness on-chain.
+    StatusVerified,
+    /// VRF Account's callback was invoked successfully.
+    StatusCallbackSuccess,
+    /// Failed to verify VRF proof.
+    StatusVerifyFailure,
+}
+impl std::fmt::Display for VrfStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            VrfStatus::StatusNone => write!(f, "StatusNone"),
+            VrfStatus::StatusRequesting => write!(f, "StatusRequesting"),
+            VrfStatus::StatusVerifying => write!(f, "StatusVerifying"),
+            VrfStatus::StatusVerified => write!(f, "StatusVerified"),
+            VrfStatus::StatusCallbackSuccess => write!(f, "StatusCallbackSuccess"),
+            VrfStatus::StatusVerifyFailure => write!(f, "StatusVerifyFailure"),
+        }
+    }
+}
+
+impl TryFrom<u8> for VrfStatus {
+    type Error = anchor_lang::error::Error;
+
+    fn try_from(value: u8) -> anchor_lang::Result<Self> {
+        match value {
+            0 => Ok(VrfStatus::StatusNone),
+            1 => Ok(VrfStatus::StatusRequesting),
+            2 => Ok(VrfStatus::StatusVerifying),
+            3 => Ok(VrfStatus::StatusVerified),
+            4 => Ok(VrfStatus::StatusCallbackSuccess),
+            5 => Ok(VrfStatus::StatusVerifyFailure),
+            _ => Err(error!(SwitchboardError::InvalidVrfStatus)),
+        }
+    }
+}
+
+impl VrfStatus {
+    /// Returns a description of the status and what the VRF account is waiting on.
+    pub fn explain(&self) -> &'static str {
+        match self {
+            VrfStatus::StatusNone => "randomness has not been requested yet",
+            VrfStatus::StatusRequesting => "waiting for the assigned oracle to submit a VRF proof",
+            VrfStatus::StatusVerifying => {
+                "a VRF proof was submitted and is waiting on verify transactions to complete"
+            }
+            VrfStatus::StatusVerified => {
+                "the VRF proof was verified and the callback has not succeeded yet"
+            }
+            VrfStatus::StatusCallbackSuccess => {
+                "the VRF proof was verified and the callback succeeded"
+            }
+            VrfStatus::StatusVerifyFailure => {
+                "the VRF proof failed verification, a new round must be requested"
+            }
+        }
+    }
+
+    /// Returns true while a requested round has not been verified or failed.
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            VrfStatus::StatusRequesting | VrfStatus::StatusVerifying
+        )
+    }
+}
+
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+pub struct EcvrfProofZC {
+    pub Gamma: EdwardsPointZC, // RistrettoPoint
+    pub c: Scalar,
+    pub s: Scalar,
+}
+impl Default for EcvrfProofZC {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+/// The `Scalar` struct holds an integer \\(s < 2\^{255} \\) which
+/// represents an element of \\(\mathbb Z / \ell\\).
+#[zero_copy(unsafe)]
+#[derive(Default)]
+#[repr(C, packed)]
+pub struct Scalar {
+    /// `bytes` is a little-endian byte encoding of an integer representing a scalar modulo the
+    /// group order.
+    ///
+    /// # Invariant
+    ///
+    /// The integer representing this scalar must be bounded above by \\(2\^{255}\\), or
+    /// equivalently the high bit of `bytes[31]` must be zero.
+    ///
+    /// This ensures that there is room for a carry bit when computing a NAF representation.
+    pub(crate) bytes: [u8; 32],
+}
+
+/// A `FieldElement51` represents an element of the field
+/// \\( \mathbb Z / (2\^{255} - 19)\\).
+///
+/// In the 64-bit implementation, a `FieldElement` is represented in
+/// radix \\(2\^{51}\\) as five `u64`s; the coefficients are allowed to
+/// grow up to \\(2\^{54}\\) between reductions modulo \\(p\\).
+///
+/// # Note
+///
+/// The `curve25519_dalek::field` module provides a type alias
+/// `curve25519_dalek::field::FieldElement` to either `FieldElement51`
+/// or `FieldElement2625`.
+///
+/// The backend-specific type `FieldElement51` should not be used
+/// outside of the `curve25519_dalek::field` module.
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct FieldElement51(pub(crate) [u64; 5]);
+unsafe impl Pod for FieldElement51 {}
+unsafe impl Zeroable for FieldElement51 {}
+
+#[zero_copy(unsafe)]
+#[derive(Default)]
+#[repr(C, packed)]
+pub struct FieldElementZC {
+    pub(crate) bytes: [u64; 5],
+}
+impl From<FieldElement51> for FieldElementZC {
+    fn from(value: FieldElement51) -> Self {
+        FieldElementZC { bytes: value.0 }
+    }
+}
+impl From<FieldElementZC> for FieldElement51 {
+    fn from(value: FieldElementZC) -> Self {
+        FieldElement51(value.bytes)
+    }
+}
+
+/// A `CompletedPoint` is a point \\(((X:Z), (Y:T))\\) on the \\(\mathbb
+/// P\^1 \times \mathbb P\^1 \\) model of the curve.
+/// A point (x,y) in the affine model corresponds to \\( ((x:1),(y:1))
+/// \\).
+///
+/// More details on the relationships between the different curve models
+/// can be found in the module-level documentation.
+#[allow(missing_docs)]
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct CompletedPoint {
+    pub X: FieldElement51,
+    pub Y: FieldElement51,
+    pub Z: FieldElement51,
+    pub T: FieldElement51,
+}
+unsafe impl Pod for CompletedPoint {}
+unsafe impl Zeroable for CompletedPoint {}
+
+#[zero_copy(unsafe)]
+#[derive(Default)]
+#[repr(C, packed)]
+pub struct CompletedPointZC {
+    pub X: FieldElementZC,
+    pub Y: FieldElementZC,
+    pub Z: FieldElementZC,
+    pub T: FieldElementZC,
+}
+impl From<CompletedPoint> for CompletedPointZC {
+    fn from(value: CompletedPoint) -> Self {
+        CompletedPointZC {
+            X: value.X.into(),
+            Y: value.Y.into(),
+            Z: value.Z.into(),
+            T: value.T.into(),
+        }
+    }
+}
+impl From<CompletedPointZC> for CompletedPoint {
+    fn from(value: CompletedPointZC) -> Self {
+        CompletedPoint {
+            X: value.X.into(),
+            Y: value.Y.into(),
+            Z: value.Z.into(),
+            T: value.T.into(),
+        }
+    }
+}
+
+/// An `EdwardsPoint` represents a point on the Edwards form of Curve25519.
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct EdwardsPoint {
+    pub(crate) X: FieldElement51,
+    pub(crate) Y: FieldElement51,
+    pub(crate) Z: FieldElement51,
+    pub(crate) T: FieldElement51,
+}
+unsafe impl Pod for EdwardsPoint {}
+unsafe impl Zeroable for EdwardsPoint {}
+
+#[zero_copy(unsafe)]
+#[derive(Default)]
+#[repr(C, packed)]
+pub struct EdwardsPointZC {
+    pub(crate) X: FieldElementZC,
+    pub(crate) Y: FieldElementZC,
+    pub(crate) Z: FieldElementZC,
+    pub(crate) T: FieldElementZC,
+}
+impl From<EdwardsPoint> for EdwardsPointZC {
+    fn from(value: EdwardsPoint) -> Self {
+        EdwardsPointZC {
+            X: value.X.into(),
+            Y: value.Y.into(),
+            Z: value.Z.into(),
+            T: value.T.into(),
+        }
+    }
+}
+impl From<EdwardsPointZC> for EdwardsPoint {
+    fn from(value: EdwardsPointZC) -> Self {
+        EdwardsPoint {
+            X: value.X.into(),
+            Y: value.Y.into(),
+            Z: value.Z.into(),
+            T: value.T.into(),
+        }
+    }
+}
+
+/// A `ProjectivePoint` is a point \\((X:Y:Z)\\) on the \\(\mathbb
+/// P\^2\\) model of the curve.
+/// A point \\((x,y)\\) in the affine model corresponds to
+/// \\((x:y:1)\\).
+///
+/// More details on the relationships between the different curve models
+/// can be found in the module-level documentation.
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct ProjectivePoint {
+    pub X: FieldElement51,
+    pub Y: FieldElement51,
+    pub Z: FieldElement51,
+}
+unsafe impl Pod for ProjectivePoint {}
+unsafe impl Zeroable for ProjectivePoint {}
+
+#[zero_copy(unsafe)]
+#[derive(Default)]
+#[repr(C, packed)]
+pub struct ProjectivePointZC {
+    pub(crate) X: FieldElementZC,
+    pub(crate) Y: FieldElementZC,
+    pub(crate) Z: FieldElementZC,
+}
+impl From<ProjectivePoint> for ProjectivePointZC {
+    fn from(value: ProjectivePoint) -> Self {
+        ProjectivePointZC {
+            X: value.X.into(),
+            Y: value.Y.into(),
+            Z: value.Z.into(),
+        }
+    }
+}
+impl From<ProjectivePointZC> for ProjectivePoint {
+    fn from(value: ProjectivePointZC) -> Self {
+        ProjectivePoint {
+            X: value.X.into(),
+            Y: value.Y.into(),
+            Z: value.Z.into(),
+        }
+    }
+}
+
+#[zero_copy(unsafe)]
+#[derive(Default)]
+#[repr(C, packed)]
+pub struct EcvrfIntermediate {
+    pub r: FieldElementZC,
+    pub N_s: FieldElementZC,
+    pub D: FieldElementZC,
+    pub t13: FieldElementZC,
+    pub t15: FieldElementZC,
+}
+
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+pub struct VrfBuilder {
+    /// The OracleAccountData that is producing the randomness.
+    pub producer: Pubkey,
+    /// The current status of the VRF verification as a raw [`VrfStatus`], see [`Self::status`].
+    pub status: u8,
+    /// The VRF proof sourced from the producer.
+    pub repr_proof: [u8; 80],
+    pub proof: EcvrfProofZC,
+    pub Y_point: Pubkey,
+    pub stage: u32,
+    pub stage1_out: EcvrfIntermediate,
+    pub R_1: EdwardsPointZC, // Ristretto
+    pub R_2: EdwardsPointZC, // Ristretto
+    pub stage3_out: EcvrfIntermediate,
+    pub H_point: EdwardsPointZC, // Ristretto
+    pub s_reduced: Scalar,
+    pub Y_point_builder: [FieldElementZC; 3],
+    pub Y_ristretto_point: EdwardsPointZC, // Ristretto
+    pub mul_round: u8,
+    pub hash_points_round: u8,
+    pub mul_tmp1: CompletedPointZC,
+    pub U_point1: EdwardsPointZC, // Ristretto
+    pub U_point2: EdwardsPointZC, // Ristretto
+    pub V_point1: EdwardsPointZC, // Ristretto
+    pub V_point2: EdwardsPointZC, // Ristretto
+    pub U_point: EdwardsPointZC,  // Ristretto
+    pub V_point: EdwardsPointZC,  // Ristretto
+    pub u1: FieldElementZC,
+    pub u2: FieldElementZC,
+    pub invertee: FieldElementZC,
+    pub y: FieldElementZC,
+    pub z: FieldElementZC,
+    pub p1_bytes: [u8; 32],
+    pub p2_bytes: [u8; 32],
+    pub p3_bytes: [u8; 32],
+    pub p4_bytes: [u8; 32],
+    pub c_prime_hashbuf: [u8; 16],
+    pub m1: FieldElementZC,
+    pub m2: FieldElementZC,
+    /// The number of transactions remaining to verify the VRF proof.
+    pub tx_remaining: u32,
+    /// Whether the VRF proof has been verified on-chain.
+    pub verified: bool,
+    /// The VRF proof verification result. Will be zeroized if still awaiting fulfillment.
+    pub result: [u8; 32],
+}
+impl Default for VrfBuilder {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl VrfBuilder {
+    /// Returns the current status of the VRF verification, or an error if the stored byte is
+    /// not a known status.
+    pub fn status(&self) -> anchor_lang::Result<VrfStatus> {
+        VrfStatus::try_from(self.status)
+    }
+
+    /// Returns a summary of how far the builder got verifying its producer's proof.
+    pub fn report(&self) -> VrfBuilderReport {
+        let status = self.status().ok();
+        let verified = self.verified;
+
+        VrfBuilderReport {
+            producer: self.producer,
+            status,
+            stage: self.stage,
+            tx_remaining: self.tx_remaining,
+            has_proof: self.repr_proof != [0u8; 80],
+            verified,
+            failed: status == Some(VrfStatus::StatusVerifyFailure),
+        }
+    }
+}
+
+/// A decoded summary of a [`VrfBuilder`]'s verification state.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VrfBuilderReport {
+    /// The OracleAccountData that is producing the randomness.
+    pub producer: Pubkey,
+    /// The current status of the VRF verification, `None` if the stored byte is not a known status.
+    pub status: Option<VrfStatus>,
+    /// The verification stage the builder reached.
+    pub stage: u32,
+    /// The number of verify transactions remaining before the proof is verified.
+    pub tx_remaining: u32,
+    /// Whether the producer has submitted a proof.
+    pub has_proof: bool,
+    /// Whether the VRF proof has been verified on-chain.
+    pub verified: bool,
+    /// Whether the VRF proof was marked as failed.
+    pub failed: bool,
+}
+
+impl VrfBuilderReport {
+    /// Returns true if the builder holds a proof that is still waiting on verify transactions.
+    pub fn is_pending(&self) -> bool {
+        self.has_proof && !self.verified && !self.failed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct TestAccounts(Vec<AccountMeta>);
+    impl ToAccountMetas for TestAccounts {
+        fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+            self.0.clone()
+        }
+    }
+
+    #[derive(AnchorSerialize)]
+    struct TestData(Vec<u8>);
+    impl Discriminator for TestData {
+        const DISCRIMINATOR: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+    }
+    impl InstructionData for TestData {}
+
+    #[test]
+    fn test_callback_new() {
+        let program_id = Pubkey::new_unique();
+        let accounts = TestAccounts(vec![
+            AccountMeta::new(Pubkey::new_unique(), false),
+            AccountMeta::new_readonly(Pubkey::new_unique(), true),
+        ]);
+        let callback = Callback::new(program_id, accounts.clone(), TestData(vec![9])).unwrap();
+        assert_eq!(callback.program_id, program_id);
+        assert_eq!(callback.accounts.len(), 2);
+        assert_eq!(AccountMeta::from(callback.accounts[1]), accounts.0[1]);
+        // discriminator, vec length prefix, then the data
+        assert_eq!(
+            callback.ix_data,
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 1, 0, 0, 0, 9]
+        );
+
+        let too_many_accounts = TestAccounts(vec![
+            AccountMeta::new(Pubkey::new_unique(), false);
+            CallbackZC::MAX_ACCOUNTS + 1
+        ]);
+        assert_eq!(
+            Callback::new(program_id, too_many_accounts, TestData(vec![])).unwrap_err(),
+            SwitchboardError::CallbackTooManyAccounts.into()
+        );
+
+        // 8 discriminator bytes and 4 length bytes leave room for 1012 bytes of data
+        assert!(Callback::new(program_id, accounts.clone(), TestData(vec![0; 1012])).is_ok());
+        assert_eq!(
+            Callback::new(program_id, accounts, TestData(vec![0; 1013])).unwrap_err(),
+            SwitchboardError::CallbackIxDataTooLarge.into()
+        );
+    }
+
+    #[test]
+    fn test_callback_zc_round_trip() {
+        let callback = Callback {
+            program_id: Pubkey::new_unique(),
+            accounts: (0..CallbackZC::MAX_ACCOUNTS)
+                .map(|i| AccountMetaBorsh {
+                    pubkey: Pubkey::new_unique(),
+                    is_signer: i % 2 == 0,
+                    is_writable: i % 3 == 0,
+                })
+                .collect(),
+            ix_data: (0..CallbackZC::MAX_IX_DATA_LEN).map(|i| i as u8).collect(),
+        };
+        let callback_zc = CallbackZC::try_from(callback.clone()).unwrap();
+        assert_eq!({ callback_zc.accounts_len }, 32);
+        assert_eq!({ callback_zc.ix_data_len }, 1024);
+        assert_eq!(Callback::try_from(callback_zc).unwrap(), callback);
+
+        let empty = Callback {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![],
+            ix_data: vec![],
+        };
+        assert_eq!(
+            Callback::try_from(CallbackZC::try_from(empty.clone()).unwrap()).unwrap(),
+            empty
+        );
+
+        // lengths past the fixed size buffers, as in a corrupt account, are rejected
+        let mut corrupt = callback_zc;
+        corrupt.accounts_len = 33;
+        assert_eq!(
+            Callback::try_from(corrupt).unwrap_err(),
+            SwitchboardError::CallbackTooManyAccounts.into()
+        );
+        let mut corrupt = callback_zc;
+        corrupt.ix_data_len = 1025;
+        assert_eq!(
+            Callback::try_from(corrupt).unwrap_err(),
+            SwitchboardError::CallbackIxDataTooLarge.into()
+        );
+
+        let mut too_large = callback;
+        too_large.ix_data.push(0);
+        assert!(CallbackZC::try_from(too_large).is_err());
+    }
+
+    #[test]
+    fn test_status_explain() {
+        for status in [
+            VrfStatus::StatusNone,
+            VrfStatus::StatusRequesting,
+            VrfStatus::StatusVerifying,
+            VrfStatus::StatusVerified,
+            VrfStatus::StatusCallbackSuccess,
+            VrfStatus::StatusVerifyFailure,
+        ] {
+            assert!(!status.explain().is_empty());
+            assert_eq!(VrfStatus::try_from(status as u8).unwrap(), status);
+        }
+        assert!(VrfStatus::try_from(6).is_err());
+
+        assert!(VrfStatus::StatusVerifying.is_pending());
+        assert!(!VrfStatus::StatusVerifyFailure.is_pending());
+    }
+
+    #[test]
+    fn test_builder_reports() {
+        let producer = Pubkey::new_unique();
+        let mut vrf = VrfAccountData::default();
+        vrf.builders[0] = VrfBuilder {
+            producer,
+            status: VrfStatus::StatusVerifying as u8,
+            repr_proof: [1u8; 80],
+            stage: 4,
+            tx_remaining: 180,
+            ..Default::default()
+        };
+        vrf.builders[1] = VrfBuilder {
+            status: VrfStatus::StatusVerifyFailure as u8,
+            repr_proof: [1u8; 80],
+            ..Default::default()
+        };
+        vrf.builders[2].tx_remaining = 1;
+
+        assert!(vrf.builder_reports().is_empty());
+        vrf.builders_len = 2;
+        let reports = vrf.builder_reports();
+        assert_eq!(
+            reports[0],
+            VrfBuilderReport {
+                producer,
+                status: Some(VrfStatus::StatusVerifying),
+                stage: 4,
+                tx_remaining: 180,
+                has_proof: true,
+                verified: false,
+                failed: false,
+            }
+        );
+        assert!(reports[0].is_pending());
+        assert!(reports[1].failed);
+        assert!(!reports[1].is_pending());
+
+        vrf.builders[1].status = 6;
+        assert_eq!(
+            vrf.builders[1].status().unwrap_err(),
+            SwitchboardError::InvalidVrfStatus.into()
+        );
+        assert_eq!(vrf.builder_reports()[1].status, None);
+
+        let vrf_lite = VrfLiteAccountData::default();
+        assert!(!vrf_lite.builder_report().has_proof);
+        assert!(!vrf_lite.builder_report().is_pending());
+    }
+}
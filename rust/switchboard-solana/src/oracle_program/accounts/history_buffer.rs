@@ -1,20 +1,12 @@
 use crate::prelude::*;
-use bytemuck::{try_cast_slice, try_from_bytes};
-use bytemuck::{Pod, Zeroable};
+use bytemuck::try_cast_slice;
+use rust_decimal::{Decimal, MathematicalOps};
 use std::cell::Ref;
+use std::iter::Chain;
+use std::slice::Iter;
 use superslice::*;
-
-#[zero_copy(unsafe)]
-#[derive(Default, Debug)]
-#[repr(packed)]
-pub struct AggregatorHistoryRow {
-    /// The timestamp of the sample.
-    pub timestamp: i64,
-    /// The value of the sample.
-    pub value: SwitchboardDecimal,
-}
-unsafe impl Pod for AggregatorHistoryRow {}
-unsafe impl Zeroable for AggregatorHistoryRow {}
+pub use switchboard_core::types::AggregatorHistoryRow;
+use switchboard_core::{decode, DecodeError};
 
 pub struct AggregatorHistoryBuffer<'a> {
     /// The current index of the round robin buffer.
@@ -32,19 +24,178 @@ impl<'a> AggregatorHistoryBuffer<'a> {
     pub fn new(
         history_buffer: &'a AccountInfo,
     ) -> anchor_lang::Result<AggregatorHistoryBuffer<'a>> {
+        if *history_buffer.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
+        }
         let data = history_buffer.try_borrow_data()?;
 
-        let mut disc_bytes = [0u8; 8];
-        disc_bytes.copy_from_slice(&data[..8]);
-        if disc_bytes != AggregatorHistoryBuffer::discriminator() {
-            return Err(SwitchboardError::AccountDiscriminatorMismatch.into());
+        let view = AggregatorHistoryView::new_from_bytes(&data)?;
+        let insertion_idx = view.insertion_idx;
+        let rows_end = 12 + std::mem::size_of_val(view.rows);
+        let rows = Ref::filter_map(data, |data| try_cast_slice(&data[12..rows_end]).ok())
+            .map_err(|_| error!(SwitchboardError::AccountDeserializationError))?;
+        Ok(Self {
+            insertion_idx,
+            rows,
+        })
+    }
+
+    /// Returns a read-only view of the history buffer from a raw account data buffer. Useful
+    /// for decoding history buffers off-chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A Solana AccountInfo's data buffer
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::AggregatorHistoryBuffer;
+    ///
+    /// let account = rpc.get_account(&history_buffer_pubkey)?;
+    /// let history = AggregatorHistoryBuffer::new_from_bytes(&account.data)?;
+    /// let latest = history.latest();
+    /// ```
+    pub fn new_from_bytes(data: &[u8]) -> anchor_lang::Result<AggregatorHistoryView<'_>> {
+        AggregatorHistoryView::new_from_bytes(data)
+    }
+
+    /// Returns a read-only view over the rows of the history buffer.
+    pub fn view(&self) -> AggregatorHistoryView<'_> {
+        AggregatorHistoryView {
+            insertion_idx: self.insertion_idx,
+            rows: &self.rows,
         }
+    }
 
-        let insertion_idx: u32 = *try_from_bytes::<u32>(&data[8..12]).unwrap();
-        return Ok(Self {
-            insertion_idx: insertion_idx as usize,
-            rows: Ref::map(data, |data| try_cast_slice(&data[12..]).unwrap()),
-        });
+    /// Return the previous row in the history buffer for a given timestamp
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - A unix timestamp to search in the history buffer
+    pub fn lower_bound(&self, timestamp: i64) -> Option<AggregatorHistoryRow> {
+        self.view().lower_bound(timestamp)
+    }
+
+    /// Return the next row in the history buffer for a given timestamp
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - A unix timestamp to search in the history buffer
+    pub fn upper_bound(&self, timestamp: i64) -> Option<AggregatorHistoryRow> {
+        self.view().upper_bound(timestamp)
+    }
+
+    /// Returns an iterator over the populated rows in chronological order. Use `.rev()` to
+    /// iterate from the most recent row.
+    pub fn iter(&self) -> AggregatorHistoryIter<'_> {
+        self.view().iter()
+    }
+
+    /// Returns an iterator over the populated rows within the inclusive window in
+    /// chronological order.
+    pub fn range(
+        &self,
+        start: i64,
+        end: i64,
+    ) -> impl DoubleEndedIterator<Item = AggregatorHistoryRow> + '_ {
+        self.view().range(start, end)
+    }
+
+    /// Returns the most recently inserted row.
+    pub fn latest(&self) -> Option<AggregatorHistoryRow> {
+        self.view().latest()
+    }
+
+    /// Returns the oldest populated row.
+    pub fn oldest(&self) -> Option<AggregatorHistoryRow> {
+        self.view().oldest()
+    }
+
+    /// Returns the number of populated rows.
+    pub fn len(&self) -> usize {
+        self.view().len()
+    }
+
+    /// Returns true if no rows have been populated.
+    pub fn is_empty(&self) -> bool {
+        self.view().is_empty()
+    }
+
+    /// Returns the time-weighted average value over the inclusive window, see
+    /// [`AggregatorHistoryView::twap`].
+    pub fn twap(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        self.view().twap(start, end)
+    }
+
+    /// Returns the simple mean of the samples within the inclusive window.
+    pub fn mean(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        self.view().mean(start, end)
+    }
+
+    /// Returns the minimum sample within the inclusive window.
+    pub fn min(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        self.view().min(start, end)
+    }
+
+    /// Returns the maximum sample within the inclusive window.
+    pub fn max(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        self.view().max(start, end)
+    }
+
+    /// Returns the realized volatility over the inclusive window, see
+    /// [`AggregatorHistoryView::realized_volatility`].
+    pub fn realized_volatility(
+        &self,
+        start: i64,
+        end: i64,
+    ) -> anchor_lang::Result<SwitchboardDecimal> {
+        self.view().realized_volatility(start, end)
+    }
+
+    /// Returns the maximum drawdown over the inclusive window, see
+    /// [`AggregatorHistoryView::max_drawdown`].
+    pub fn max_drawdown(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        self.view().max_drawdown(start, end)
+    }
+
+    /// Returns the change between the values in effect at two timestamps, see
+    /// [`AggregatorHistoryView::relative_change`].
+    pub fn relative_change(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        self.view().relative_change(start, end)
+    }
+}
+
+/// A read-only view over the rows of a history buffer, borrowed either from an
+/// [`AggregatorHistoryBuffer`] or directly from the account data.
+#[derive(Clone, Copy, Debug)]
+pub struct AggregatorHistoryView<'a> {
+    /// The current index of the round robin buffer.
+    pub insertion_idx: usize,
+    /// The array of samples collected from the aggregator.
+    pub rows: &'a [AggregatorHistoryRow],
+}
+
+impl<'a> AggregatorHistoryView<'a> {
+    /// Returns a view of the history buffer from a raw account data buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A Solana AccountInfo's data buffer
+    pub fn new_from_bytes(data: &'a [u8]) -> anchor_lang::Result<AggregatorHistoryView<'a>> {
+        let (insertion_idx, rows) = decode::history_buffer(data).map_err(|err| match err {
+            DecodeError::DiscriminatorMismatch => {
+                SwitchboardError::AccountDiscriminatorMismatch.into()
+            }
+            err => decode_error(err),
+        })?;
+        let rows: &[AggregatorHistoryRow] = try_cast_slice(rows)
+            .map_err(|_| error!(SwitchboardError::AccountDeserializationError))?;
+
+        Ok(Self {
+            insertion_idx,
+            rows,
+        })
     }
 
     /// Return the previous row in the history buffer for a given timestamp
@@ -53,9 +204,7 @@ impl<'a> AggregatorHistoryBuffer<'a> {
     ///
     /// * `timestamp` - A unix timestamp to search in the history buffer
     pub fn lower_bound(&self, timestamp: i64) -> Option<AggregatorHistoryRow> {
-        if self.rows[self.insertion_idx].timestamp == 0 {
-            return None;
-        }
+        self.latest()?;
         let lower = &self.rows[..self.insertion_idx + 1];
         let lahr = lower.lower_bound_by(|x| {
             let other: i64 = x.timestamp;
@@ -85,6 +234,321 @@ impl<'a> AggregatorHistoryBuffer<'a> {
         }
         None
     }
+
+    /// Return the next row in the history buffer for a given timestamp, the first row with a
+    /// timestamp at or after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - A unix timestamp to search in the history buffer
+    pub fn upper_bound(&self, timestamp: i64) -> Option<AggregatorHistoryRow> {
+        let (older, newer) = self.split();
+        for half in [older, newer] {
+            if half.is_empty() || half[0].timestamp == 0 {
+                continue;
+            }
+            let idx = half.lower_bound_by(|x| {
+                let other: i64 = x.timestamp;
+                other.cmp(&timestamp)
+            });
+            if idx < half.len() {
+                return Some(half[idx]);
+            }
+        }
+        None
+    }
+
+    /// Returns an iterator over the populated rows in chronological order. Use `.rev()` to
+    /// iterate from the most recent row.
+    pub fn iter(&self) -> AggregatorHistoryIter<'a> {
+        let (older, newer) = self.split();
+        AggregatorHistoryIter {
+            inner: older.iter().chain(newer.iter()),
+        }
+    }
+
+    /// Returns an iterator over the populated rows within the inclusive window in
+    /// chronological order.
+    pub fn range(
+        &self,
+        start: i64,
+        end: i64,
+    ) -> impl DoubleEndedIterator<Item = AggregatorHistoryRow> + 'a {
+        self.iter()
+            .filter(move |row| row.timestamp >= start && row.timestamp <= end)
+    }
+
+    /// Returns the most recently inserted row.
+    pub fn latest(&self) -> Option<AggregatorHistoryRow> {
+        self.rows
+            .get(self.insertion_idx)
+            .filter(|row| row.timestamp != 0)
+            .copied()
+    }
+
+    /// Returns the oldest populated row.
+    pub fn oldest(&self) -> Option<AggregatorHistoryRow> {
+        self.iter().next()
+    }
+
+    /// Returns the number of populated rows.
+    pub fn len(&self) -> usize {
+        self.rows.iter().filter(|row| row.timestamp != 0).count()
+    }
+
+    /// Returns true if no rows have been populated.
+    pub fn is_empty(&self) -> bool {
+        self.latest().is_none()
+    }
+
+    /// Returns the time-weighted average value over the inclusive window. Each sample is
+    /// weighted by the number of seconds until the next sample or the end of the window. The
+    /// sample in effect at the start of the window is included; if there is none, the average
+    /// starts from the first sample inside the window.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The unix timestamp of the start of the window
+    /// * `end` - The unix timestamp of the end of the window
+    pub fn twap(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        if start > end {
+            return Err(SwitchboardError::InvalidFunctionInput.into());
+        }
+
+        let mut current: Option<(i64, Decimal)> = None;
+        let mut weighted_sum = Decimal::ZERO;
+        let mut total_duration: i64 = 0;
+        for row in self.iter() {
+            let value: Decimal = row.value.try_into()?;
+            if row.timestamp <= start {
+                current = Some((start, value));
+                continue;
+            }
+            if row.timestamp > end {
+                break;
+            }
+            if let Some((timestamp, current_value)) = current {
+                let duration = row.timestamp - timestamp;
+                weighted_sum = weighted_sum
+                    .checked_add(
+                        current_value
+                            .checked_mul(Decimal::from(duration))
+                            .ok_or(error!(SwitchboardError::IntegerOverflowError))?,
+                    )
+                    .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+                total_duration += duration;
+            }
+            current = Some((row.timestamp, value));
+        }
+
+        let (timestamp, current_value) =
+            current.ok_or(error!(SwitchboardError::InsufficientHistory))?;
+        let duration = end - timestamp;
+        weighted_sum = weighted_sum
+            .checked_add(
+                current_value
+                    .checked_mul(Decimal::from(duration))
+                    .ok_or(error!(SwitchboardError::IntegerOverflowError))?,
+            )
+            .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+        total_duration += duration;
+
+        if total_duration == 0 {
+            return Ok(current_value.into());
+        }
+        let twap = weighted_sum
+            .checked_div(Decimal::from(total_duration))
+            .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+        Ok(twap.into())
+    }
+
+    /// Returns the simple mean of the samples within the inclusive window.
+    pub fn mean(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        let mut sum = Decimal::ZERO;
+        let mut count: u32 = 0;
+        for value in self.window_values(start, end)? {
+            sum = sum
+                .checked_add(value?)
+                .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+            count += 1;
+        }
+        if count == 0 {
+            return Err(SwitchboardError::InsufficientHistory.into());
+        }
+        let mean = sum
+            .checked_div(Decimal::from(count))
+            .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+        Ok(mean.into())
+    }
+
+    /// Returns the minimum sample within the inclusive window.
+    pub fn min(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        let mut min: Option<Decimal> = None;
+        for value in self.window_values(start, end)? {
+            let value = value?;
+            min = Some(min.map_or(value, |min| min.min(value)));
+        }
+        min.map(SwitchboardDecimal::from)
+            .ok_or(error!(SwitchboardError::InsufficientHistory))
+    }
+
+    /// Returns the maximum sample within the inclusive window.
+    pub fn max(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        let mut max: Option<Decimal> = None;
+        for value in self.window_values(start, end)? {
+            let value = value?;
+            max = Some(max.map_or(value, |max| max.max(value)));
+        }
+        max.map(SwitchboardDecimal::from)
+            .ok_or(error!(SwitchboardError::InsufficientHistory))
+    }
+
+    /// Returns the realized volatility over the inclusive window, the sample standard deviation
+    /// of the log returns between consecutive samples. The result is per sample and is not
+    /// annualized. Requires at least three positive samples within the window.
+    ///
+    /// The rows are streamed in a single pass with Welford's algorithm, so no memory is
+    /// allocated and each log return is computed once.
+    pub fn realized_volatility(
+        &self,
+        start: i64,
+        end: i64,
+    ) -> anchor_lang::Result<SwitchboardDecimal> {
+        let mut previous: Option<Decimal> = None;
+        let mut count: u32 = 0;
+        let mut mean = Decimal::ZERO;
+        let mut sum_squares = Decimal::ZERO;
+        for value in self.window_values(start, end)? {
+            let value = value?;
+            if value <= Decimal::ZERO {
+                return Err(SwitchboardError::DecimalConversionError.into());
+            }
+            let Some(previous_value) = previous.replace(value) else {
+                continue;
+            };
+            let log_return = value
+                .checked_div(previous_value)
+                .and_then(|ratio| ratio.checked_ln())
+                .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+
+            count += 1;
+            let deviation = log_return
+                .checked_sub(mean)
+                .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+            mean = deviation
+                .checked_div(Decimal::from(count))
+                .and_then(|step| mean.checked_add(step))
+                .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+            sum_squares = log_return
+                .checked_sub(mean)
+                .and_then(|new_deviation| deviation.checked_mul(new_deviation))
+                .and_then(|square| sum_squares.checked_add(square))
+                .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+        }
+        if count < 2 {
+            return Err(SwitchboardError::InsufficientHistory.into());
+        }
+
+        let volatility = sum_squares
+            .checked_div(Decimal::from(count - 1))
+            .and_then(|variance| variance.sqrt())
+            .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+        Ok(volatility.into())
+    }
+
+    /// Returns the maximum drawdown over the inclusive window, the largest decline from a
+    /// running peak as a fraction of that peak (0.05 = 5%). Requires positive samples.
+    pub fn max_drawdown(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        let mut peak: Option<Decimal> = None;
+        let mut max_drawdown = Decimal::ZERO;
+        for value in self.window_values(start, end)? {
+            let value = value?;
+            if value <= Decimal::ZERO {
+                return Err(SwitchboardError::DecimalConversionError.into());
+            }
+            let peak = match peak {
+                Some(peak) if peak >= value => peak,
+                _ => {
+                    peak = Some(value);
+                    continue;
+                }
+            };
+            let drawdown = peak
+                .checked_sub(value)
+                .and_then(|decline| decline.checked_div(peak))
+                .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+            max_drawdown = max_drawdown.max(drawdown);
+        }
+        if peak.is_none() {
+            return Err(SwitchboardError::InsufficientHistory.into());
+        }
+        Ok(max_drawdown.into())
+    }
+
+    /// Returns the percentage change from the value in effect at `start` to the value in effect
+    /// at `end`, expressed as a fraction of the starting value (0.05 = 5% increase).
+    pub fn relative_change(&self, start: i64, end: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        if start > end {
+            return Err(SwitchboardError::InvalidFunctionInput.into());
+        }
+        let from: Decimal = self
+            .lower_bound(start)
+            .ok_or(error!(SwitchboardError::InsufficientHistory))?
+            .value
+            .try_into()?;
+        let to: Decimal = self
+            .lower_bound(end)
+            .ok_or(error!(SwitchboardError::InsufficientHistory))?
+            .value
+            .try_into()?;
+        if from.is_zero() {
+            return Err(SwitchboardError::DecimalConversionError.into());
+        }
+        let change = to
+            .checked_sub(from)
+            .and_then(|delta| delta.checked_div(from))
+            .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+        Ok(change.into())
+    }
+
+    /// Splits the round robin buffer around the insertion index into the older and newer rows.
+    fn split(&self) -> (&'a [AggregatorHistoryRow], &'a [AggregatorHistoryRow]) {
+        let split = (self.insertion_idx + 1).min(self.rows.len());
+        let (newer, older) = self.rows.split_at(split);
+        (older, newer)
+    }
+
+    /// Returns the values of the rows sampled within the inclusive window, converted lazily so
+    /// the window statistics never collect the rows into memory.
+    fn window_values(
+        &self,
+        start: i64,
+        end: i64,
+    ) -> anchor_lang::Result<impl Iterator<Item = anchor_lang::Result<Decimal>> + 'a> {
+        if start > end {
+            return Err(SwitchboardError::InvalidFunctionInput.into());
+        }
+        Ok(self.range(start, end).map(|row| Ok(row.value.try_into()?)))
+    }
+}
+
+/// Iterator over the populated rows of a history buffer in chronological order.
+pub struct AggregatorHistoryIter<'a> {
+    inner: Chain<Iter<'a, AggregatorHistoryRow>, Iter<'a, AggregatorHistoryRow>>,
+}
+
+impl<'a> Iterator for AggregatorHistoryIter<'a> {
+    type Item = AggregatorHistoryRow;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.find(|row| row.timestamp != 0).copied()
+    }
+}
+
+impl<'a> DoubleEndedIterator for AggregatorHistoryIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.rfind(|row| row.timestamp != 0).copied()
+    }
 }
 
 impl<'a> Discriminator for AggregatorHistoryBuffer<'a> {
@@ -100,6 +564,7 @@ impl<'a> Owner for AggregatorHistoryBuffer<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     // impl<'a> Default for AggregatorHistoryBuffer<'a> {
     // fn default() -> Self {
@@ -285,4 +750,246 @@ mod tests {
             Some(row) => panic!("retrieved row when no value was expected {:?}", row.value),
         };
     }
+
+    #[test]
+    fn test_history_buffer_statistics() {
+        let mut history_data = HISTORY_BUFFER_DATA;
+        let mut lamports = 0;
+        let history_account_info = AccountInfo::new(
+            &HISTORY_BUFFER_PUBKEY,
+            false,
+            false,
+            &mut lamports,
+            &mut history_data,
+            &SWITCHBOARD_PROGRAM_ID,
+            false,
+            0,
+        );
+        let history_buffer = AggregatorHistoryBuffer::new(&history_account_info).unwrap();
+        let to_decimal = |value: SwitchboardDecimal| -> Decimal { value.try_into().unwrap() };
+
+        // window within the older half of the buffer
+        let twap = to_decimal(history_buffer.twap(1646249893, 1646249911).unwrap());
+        assert_eq!(twap, Decimal::from_str("100.49316095375").unwrap());
+
+        // window spanning the insertion index
+        let twap = to_decimal(history_buffer.twap(1646249918, 1646249949).unwrap());
+        assert_eq!(
+            twap.round_dp(20),
+            Decimal::from_str("100.57242285588709677419").unwrap()
+        );
+
+        // value in effect at the start of the window is carried forward
+        let twap = to_decimal(history_buffer.twap(1646249900, 1646249960).unwrap());
+        assert_eq!(twap, Decimal::from_str("100.5429575735").unwrap());
+
+        // a zero length window returns the value in effect
+        let twap = to_decimal(history_buffer.twap(1646249905, 1646249905).unwrap());
+        assert_eq!(twap, Decimal::from_str("100.517196115").unwrap());
+
+        let mean = to_decimal(history_buffer.mean(0, i64::MAX).unwrap());
+        assert_eq!(mean, Decimal::from_str("100.51048364375").unwrap());
+        assert_eq!(
+            to_decimal(history_buffer.min(0, i64::MAX).unwrap()),
+            Decimal::from_str("100.3012875").unwrap()
+        );
+        assert_eq!(
+            to_decimal(history_buffer.max(1646249918, 1646249949).unwrap()),
+            Decimal::from_str("100.6022611525").unwrap()
+        );
+
+        assert_eq!(
+            history_buffer.twap(646249911, 646249912).unwrap_err(),
+            SwitchboardError::InsufficientHistory.into()
+        );
+        assert_eq!(
+            history_buffer.mean(1646249950, 1646249960).unwrap_err(),
+            SwitchboardError::InsufficientHistory.into()
+        );
+        assert_eq!(
+            history_buffer.min(1646249960, 1646249950).unwrap_err(),
+            SwitchboardError::InvalidFunctionInput.into()
+        );
+    }
+
+    #[test]
+    fn test_history_buffer_ordering() {
+        let history_buffer = AggregatorHistoryBuffer::new_from_bytes(&HISTORY_BUFFER_DATA).unwrap();
+
+        let timestamps: Vec<i64> = history_buffer.iter().map(|row| row.timestamp).collect();
+        assert_eq!(timestamps.len(), 10);
+        assert_eq!(timestamps[0], 1646249713);
+        assert_eq!(timestamps[9], 1646249949);
+        assert!(timestamps.windows(2).all(|w| w[0] < w[1]));
+
+        let reversed: Vec<i64> = history_buffer
+            .iter()
+            .rev()
+            .map(|row| row.timestamp)
+            .collect();
+        assert_eq!(
+            reversed,
+            timestamps.iter().rev().copied().collect::<Vec<i64>>()
+        );
+
+        assert_eq!(history_buffer.len(), 10);
+        assert!(!history_buffer.is_empty());
+        assert_eq!({ history_buffer.latest().unwrap().timestamp }, 1646249949);
+        assert_eq!({ history_buffer.oldest().unwrap().timestamp }, 1646249713);
+
+        let range: Vec<i64> = history_buffer
+            .range(1646249911, 1646249940)
+            .map(|row| row.timestamp)
+            .collect();
+        assert_eq!(range, vec![1646249911, 1646249918, 1646249929, 1646249940]);
+
+        assert_eq!(
+            { history_buffer.upper_bound(1646249912).unwrap().timestamp },
+            1646249918
+        );
+        assert_eq!(
+            { history_buffer.upper_bound(1646249930).unwrap().timestamp },
+            1646249940
+        );
+        assert_eq!(
+            { history_buffer.upper_bound(1646249911).unwrap().timestamp },
+            1646249911
+        );
+        assert_eq!(
+            { history_buffer.upper_bound(0).unwrap().timestamp },
+            1646249713
+        );
+        assert!(history_buffer.upper_bound(1646249950).is_none());
+    }
+
+    #[test]
+    fn test_history_buffer_partially_filled() {
+        let mut data = HISTORY_BUFFER_DATA;
+        // insertion_idx = 1, rows 2..10 are unfilled
+        for byte in data[12 + 2 * 28..].iter_mut() {
+            *byte = 0;
+        }
+        let history_buffer = AggregatorHistoryBuffer::new_from_bytes(&data).unwrap();
+        let timestamps: Vec<i64> = history_buffer.iter().map(|row| row.timestamp).collect();
+        assert_eq!(timestamps, vec![1646249940, 1646249949]);
+        assert_eq!(history_buffer.len(), 2);
+        assert_eq!({ history_buffer.latest().unwrap().timestamp }, 1646249949);
+        assert_eq!({ history_buffer.oldest().unwrap().timestamp }, 1646249940);
+        assert_eq!(
+            { history_buffer.upper_bound(0).unwrap().timestamp },
+            1646249940
+        );
+        assert!(history_buffer.lower_bound(1646249939).is_none());
+
+        let empty = [0u8; 292];
+        let mut empty_data = empty;
+        empty_data[..8].copy_from_slice(&HISTORY_BUFFER_DATA[..8]);
+        let history_buffer = AggregatorHistoryBuffer::new_from_bytes(&empty_data).unwrap();
+        assert!(history_buffer.is_empty());
+        assert_eq!(history_buffer.len(), 0);
+        assert!(history_buffer.iter().next().is_none());
+        assert!(history_buffer.upper_bound(0).is_none());
+    }
+
+    #[test]
+    fn test_history_buffer_risk_statistics() {
+        let history_buffer = AggregatorHistoryBuffer::new_from_bytes(&HISTORY_BUFFER_DATA).unwrap();
+        let to_decimal = |value: SwitchboardDecimal| -> Decimal { value.try_into().unwrap() };
+
+        let volatility = to_decimal(history_buffer.realized_volatility(0, i64::MAX).unwrap());
+        assert_eq!(
+            volatility.round_dp(12),
+            Decimal::from_str("0.000906644990").unwrap()
+        );
+
+        let drawdown = to_decimal(history_buffer.max_drawdown(0, i64::MAX).unwrap());
+        assert_eq!(
+            drawdown.round_dp(16),
+            Decimal::from_str("0.0010660429948317").unwrap()
+        );
+        // a single sample has no drawdown
+        let drawdown = to_decimal(history_buffer.max_drawdown(1646249902, 1646249902).unwrap());
+        assert_eq!(drawdown, Decimal::ZERO);
+        // the four samples from 1646249911 to 1646249940 only increase, so there is no drawdown
+        assert_eq!(history_buffer.range(1646249911, 1646249940).count(), 4);
+        let drawdown = to_decimal(history_buffer.max_drawdown(1646249911, 1646249940).unwrap());
+        assert_eq!(drawdown, Decimal::ZERO);
+        // the last sample falls from the peak at 1646249940
+        let drawdown = to_decimal(history_buffer.max_drawdown(1646249911, 1646249949).unwrap());
+        assert!(drawdown > Decimal::ZERO);
+        assert_eq!(
+            history_buffer.max_drawdown(0, 100).unwrap_err(),
+            SwitchboardError::InsufficientHistory.into()
+        );
+
+        let change = to_decimal(
+            history_buffer
+                .relative_change(1646249893, 1646249949)
+                .unwrap(),
+        );
+        assert_eq!(
+            change.round_dp(16),
+            Decimal::from_str("0.0005070981468001").unwrap()
+        );
+
+        assert_eq!(
+            history_buffer
+                .realized_volatility(1646249940, 1646249949)
+                .unwrap_err(),
+            SwitchboardError::InsufficientHistory.into()
+        );
+        assert_eq!(
+            history_buffer
+                .relative_change(646249911, 1646249949)
+                .unwrap_err(),
+            SwitchboardError::InsufficientHistory.into()
+        );
+    }
+
+    #[test]
+    fn test_history_buffer_rejects_malformed_accounts() {
+        let mut lamports = 0;
+        let mut history_data = HISTORY_BUFFER_DATA;
+        let wrong_owner = Pubkey::new_unique();
+        let history_account_info = AccountInfo::new(
+            &HISTORY_BUFFER_PUBKEY,
+            false,
+            false,
+            &mut lamports,
+            &mut history_data,
+            &wrong_owner,
+            false,
+            0,
+        );
+        assert_eq!(
+            AggregatorHistoryBuffer::new(&history_account_info)
+                .err()
+                .unwrap(),
+            SwitchboardError::InvalidAccountOwner.into()
+        );
+
+        for len in [0, 4, 10, 12, 20] {
+            let mut lamports = 0;
+            let mut short_data = HISTORY_BUFFER_DATA[..len].to_vec();
+            let history_account_info = AccountInfo::new(
+                &HISTORY_BUFFER_PUBKEY,
+                false,
+                false,
+                &mut lamports,
+                &mut short_data,
+                &SWITCHBOARD_PROGRAM_ID,
+                false,
+                0,
+            );
+            assert!(AggregatorHistoryBuffer::new(&history_account_info).is_err());
+        }
+
+        // insertion index out of range
+        let mut data = HISTORY_BUFFER_DATA;
+        data[8] = 10;
+        assert_eq!(
+            AggregatorHistoryBuffer::new_from_bytes(&data).unwrap_err(),
+            SwitchboardError::AccountDeserializationError.into()
+        );
+    }
 }
@@ -0,0 +1,398 @@
+//! Asserts the zero-copy account definitions match the shared switchboard-core layouts. Types
+//! re-exported from switchboard-core are asserted in its own test suite.
+
+use crate::prelude::*;
+use switchboard_core::{assert_layout, layout};
+
+#[test]
+fn test_aggregator_round_layout() {
+    assert_layout!(
+        AggregatorRound,
+        layout::AGGREGATOR_ROUND,
+        [
+            num_success,
+            num_error,
+            is_closed,
+            round_open_slot,
+            round_open_timestamp,
+            result,
+            std_deviation,
+            min_response,
+            max_response,
+            oracle_pubkeys_data,
+            medians_data,
+            current_payout,
+            medians_fulfilled,
+            errors_fulfilled
+        ]
+    );
+}
+
+#[test]
+fn test_sliding_window_element_layout() {
+    assert_layout!(
+        SlidingWindowElement,
+        layout::SLIDING_WINDOW_ELEMENT,
+        [oracle_key, value, slot, timestamp]
+    );
+}
+
+#[test]
+fn test_crank_row_layout() {
+    assert_layout!(CrankRow, layout::CRANK_ROW, [pubkey, next_timestamp]);
+}
+
+#[test]
+fn test_aggregator_account_data_layout() {
+    assert_layout!(
+        AggregatorAccountData,
+        layout::AGGREGATOR_ACCOUNT_DATA,
+        [
+            name,
+            metadata,
+            _reserved1,
+            queue_pubkey,
+            oracle_request_batch_size,
+            min_oracle_results,
+            min_job_results,
+            min_update_delay_seconds,
+            start_after,
+            variance_threshold,
+            force_report_period,
+            expiration,
+            consecutive_failure_count,
+            next_allowed_update_time,
+            is_locked,
+            crank_pubkey,
+            latest_confirmed_round,
+            current_round,
+            job_pubkeys_data,
+            job_hashes,
+            job_pubkeys_size,
+            jobs_checksum,
+            authority,
+            history_buffer,
+            previous_confirmed_round_result,
+            previous_confirmed_round_slot,
+            disable_crank,
+            job_weights,
+            creation_timestamp,
+            resolution_mode,
+            base_priority_fee,
+            priority_fee_bump,
+            priority_fee_bump_period,
+            max_priority_fee_multiplier,
+            parent_function,
+            _ebuf
+        ]
+    );
+    assert_eq!(
+        layout::AGGREGATOR_ACCOUNT_DATA.discriminator,
+        Some(AggregatorAccountData::discriminator())
+    );
+}
+
+#[test]
+fn test_sliding_result_account_data_layout() {
+    assert_layout!(
+        SlidingResultAccountData,
+        layout::SLIDING_RESULT_ACCOUNT_DATA,
+        [data, bump, _ebuf]
+    );
+    assert_eq!(
+        layout::SLIDING_RESULT_ACCOUNT_DATA.discriminator,
+        Some(SlidingResultAccountData::discriminator())
+    );
+}
+
+#[test]
+fn test_oracle_queue_account_data_layout() {
+    assert_layout!(
+        OracleQueueAccountData,
+        layout::ORACLE_QUEUE_ACCOUNT_DATA,
+        [
+            name,
+            metadata,
+            authority,
+            oracle_timeout,
+            reward,
+            min_stake,
+            slashing_enabled,
+            variance_tolerance_multiplier,
+            feed_probation_period,
+            curr_idx,
+            size,
+            gc_idx,
+            consecutive_feed_failure_limit,
+            consecutive_oracle_failure_limit,
+            unpermissioned_feeds_enabled,
+            unpermissioned_vrf_enabled,
+            curator_reward_cut,
+            lock_lease_funding,
+            mint,
+            enable_buffer_relayers,
+            _ebuf,
+            max_size,
+            data_buffer
+        ]
+    );
+    assert_eq!(
+        layout::ORACLE_QUEUE_ACCOUNT_DATA.discriminator,
+        Some(OracleQueueAccountData::discriminator())
+    );
+}
+
+#[test]
+fn test_oracle_account_data_layout() {
+    assert_layout!(
+        OracleAccountData,
+        layout::ORACLE_ACCOUNT_DATA,
+        [
+            name,
+            metadata,
+            oracle_authority,
+            last_heartbeat,
+            num_in_use,
+            token_account,
+            queue_pubkey,
+            metrics,
+            bump,
+            _ebuf
+        ]
+    );
+    assert_eq!(
+        layout::ORACLE_ACCOUNT_DATA.discriminator,
+        Some(OracleAccountData::discriminator())
+    );
+}
+
+#[test]
+fn test_lease_account_data_layout() {
+    assert_layout!(
+        LeaseAccountData,
+        layout::LEASE_ACCOUNT_DATA,
+        [
+            escrow,
+            queue,
+            aggregator,
+            token_program,
+            is_active,
+            crank_row_count,
+            created_at,
+            update_count,
+            withdraw_authority,
+            bump,
+            _ebuf
+        ]
+    );
+    assert_eq!(
+        layout::LEASE_ACCOUNT_DATA.discriminator,
+        Some(LeaseAccountData::discriminator())
+    );
+}
+
+#[test]
+fn test_permission_account_data_layout() {
+    assert_layout!(
+        PermissionAccountData,
+        layout::PERMISSION_ACCOUNT_DATA,
+        [
+            authority,
+            permissions,
+            granter,
+            grantee,
+            expiration,
+            bump,
+            _ebuf
+        ]
+    );
+    assert_eq!(
+        layout::PERMISSION_ACCOUNT_DATA.discriminator,
+        Some(PermissionAccountData::discriminator())
+    );
+}
+
+#[test]
+fn test_crank_account_data_layout() {
+    assert_layout!(
+        CrankAccountData,
+        layout::CRANK_ACCOUNT_DATA,
+        [
+            name,
+            metadata,
+            queue_pubkey,
+            pq_size,
+            max_rows,
+            jitter_modifier,
+            _ebuf,
+            data_buffer
+        ]
+    );
+    assert_eq!(
+        layout::CRANK_ACCOUNT_DATA.discriminator,
+        Some(CrankAccountData::discriminator())
+    );
+}
+
+#[test]
+fn test_sb_state_layout() {
+    assert_layout!(
+        SbState,
+        layout::SB_STATE,
+        [authority, token_mint, token_vault, dao_mint, bump, _ebuf]
+    );
+    assert_eq!(
+        layout::SB_STATE.discriminator,
+        Some(SbState::discriminator())
+    );
+}
+
+#[test]
+fn test_account_meta_zc_layout() {
+    assert_layout!(
+        AccountMetaZC,
+        layout::ACCOUNT_META_ZC,
+        [pubkey, is_signer, is_writable]
+    );
+}
+
+#[test]
+fn test_callback_zc_layout() {
+    assert_layout!(
+        CallbackZC,
+        layout::CALLBACK_ZC,
+        [program_id, accounts, accounts_len, ix_data, ix_data_len]
+    );
+}
+
+#[test]
+fn test_vrf_builder_layout() {
+    assert_layout!(
+        VrfBuilder,
+        layout::VRF_BUILDER,
+        [
+            producer,
+            status,
+            repr_proof,
+            proof,
+            Y_point,
+            stage,
+            stage1_out,
+            R_1,
+            R_2,
+            stage3_out,
+            H_point,
+            s_reduced,
+            Y_point_builder,
+            Y_ristretto_point,
+            mul_round,
+            hash_points_round,
+            mul_tmp1,
+            U_point1,
+            U_point2,
+            V_point1,
+            V_point2,
+            U_point,
+            V_point,
+            u1,
+            u2,
+            invertee,
+            y,
+            z,
+            p1_bytes,
+            p2_bytes,
+            p3_bytes,
+            p4_bytes,
+            c_prime_hashbuf,
+            m1,
+            m2,
+            tx_remaining,
+            verified,
+            result
+        ]
+    );
+}
+
+#[test]
+fn test_vrf_pool_row_layout() {
+    assert_layout!(VrfPoolRow, layout::VRF_POOL_ROW, [timestamp, pubkey]);
+}
+
+#[test]
+fn test_vrf_account_data_layout() {
+    assert_layout!(
+        VrfAccountData,
+        layout::VRF_ACCOUNT_DATA,
+        [
+            status,
+            counter,
+            authority,
+            oracle_queue,
+            escrow,
+            callback,
+            batch_size,
+            builders,
+            builders_len,
+            test_mode,
+            current_round,
+            _ebuf
+        ]
+    );
+    assert_eq!(
+        layout::VRF_ACCOUNT_DATA.discriminator,
+        Some(VrfAccountData::discriminator())
+    );
+}
+
+#[test]
+fn test_vrf_lite_account_data_layout() {
+    assert_layout!(
+        VrfLiteAccountData,
+        layout::VRF_LITE_ACCOUNT_DATA,
+        [
+            state_bump,
+            permission_bump,
+            vrf_pool,
+            status,
+            result,
+            counter,
+            alpha,
+            alpha_len,
+            request_slot,
+            request_timestamp,
+            authority,
+            queue,
+            escrow,
+            callback,
+            builder,
+            expiration
+        ]
+    );
+    assert_eq!(
+        layout::VRF_LITE_ACCOUNT_DATA.discriminator,
+        Some(VrfLiteAccountData::discriminator())
+    );
+}
+
+#[test]
+fn test_vrf_pool_account_data_layout() {
+    assert_layout!(
+        VrfPoolAccountData,
+        layout::VRF_POOL_ACCOUNT_DATA,
+        [
+            authority,
+            queue,
+            escrow,
+            min_interval,
+            max_rows,
+            size,
+            idx,
+            state_bump,
+            _ebuf
+        ]
+    );
+    assert_eq!(
+        layout::VRF_POOL_ACCOUNT_DATA.discriminator,
+        Some(VrfPoolAccountData::discriminator())
+    );
+}
@@ -1,7 +1,7 @@
 use crate::prelude::*;
 
 #[account(zero_copy(unsafe))]
-#[repr(packed)]
+#[repr(C, packed)]
 pub struct LeaseAccountData {
     /// Public key of the token account holding the lease contract funds until rewarded to oracles for successfully processing updates
     pub escrow: Pubkey, // Needed, maybe derived, key + "update_escrow"?
@@ -36,6 +36,91 @@ impl LeaseAccountData {
     pub fn size() -> usize {
         8 + std::mem::size_of::<LeaseAccountData>()
     }
+
+    /// Returns how long the lease's escrow can keep funding its aggregator's update rounds.
+    ///
+    /// Each round costs the queue's reward for every oracle in the batch plus the round opener,
+    /// see [`OracleQueueAccountData::checked_max_round_rewards`]. The time estimate assumes the
+    /// aggregator updates as often as its min update delay allows, so it is the shortest possible
+    /// runway. An aggregator without a min update delay has no such bound, so a funded lease has
+    /// no time estimate. An inactive lease funds no updates, so its runway is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `escrow_balance` - The token balance of the lease's escrow
+    /// * `queue` - The oracle queue the lease pays
+    /// * `oracle_request_batch_size` - The aggregator's `oracle_request_batch_size`
+    /// * `min_update_delay_seconds` - The aggregator's `min_update_delay_seconds`
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{AggregatorAccountData, LeaseAccountData, OracleQueueAccountData};
+    ///
+    /// let runway = lease.runway(
+    ///     escrow.amount,
+    ///     queue,
+    ///     aggregator.oracle_request_batch_size,
+    ///     aggregator.min_update_delay_seconds,
+    /// )?;
+    /// if runway.seconds_remaining.is_some_and(|seconds| seconds < 7 * 24 * 60 * 60) {
+    ///     alert("lease runs dry within a week");
+    /// }
+    /// ```
+    pub fn runway(
+        &self,
+        escrow_balance: u64,
+        queue: &OracleQueueAccountData,
+        oracle_request_batch_size: u32,
+        min_update_delay_seconds: u32,
+    ) -> anchor_lang::Result<LeaseRunway> {
+        let cost_per_update = queue.checked_max_round_rewards(oracle_request_batch_size)?;
+        let remaining_updates = if self.is_active {
+            escrow_balance.checked_div(cost_per_update)
+        } else {
+            Some(0)
+        };
+
+        Ok(LeaseRunway {
+            is_active: self.is_active,
+            cost_per_update,
+            remaining_updates,
+            seconds_remaining: match remaining_updates {
+                Some(updates) if updates > 0 && min_update_delay_seconds == 0 => None,
+                _ => remaining_updates
+                    .map(|updates| updates.saturating_mul(min_update_delay_seconds.into())),
+            },
+        })
+    }
+}
+
+/// How long a lease's escrow can fund its aggregator's update rounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LeaseRunway {
+    /// Whether the lease contract is still active. Inactive leases fund no updates.
+    pub is_active: bool,
+    /// The most a single update round costs the lease.
+    pub cost_per_update: u64,
+    /// The number of update rounds the escrow can fund. Zero if the lease is inactive, None if
+    /// the queue pays no rewards.
+    pub remaining_updates: Option<u64>,
+    /// The shortest time in seconds until the escrow runs dry. Zero if the lease is inactive,
+    /// None if the queue pays no rewards or the aggregator has no min update delay to bound how
+    /// quickly a funded escrow is spent.
+    pub seconds_remaining: Option<u64>,
+}
+
+impl LeaseRunway {
+    /// Returns the earliest unix timestamp at which the escrow can run dry, or None if
+    /// `seconds_remaining` has no estimate.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current unix timestamp
+    pub fn depleted_at(&self, now: i64) -> Option<i64> {
+        self.seconds_remaining
+            .map(|seconds| now.saturating_add(i64::try_from(seconds).unwrap_or(i64::MAX)))
+    }
 }
 
 impl TryInto<LeaseAccountData> for Option<Vec<u8>> {
@@ -44,10 +129,78 @@ impl TryInto<LeaseAccountData> for Option<Vec<u8>> {
     fn try_into(self) -> std::result::Result<LeaseAccountData, Self::Error> {
         if let Some(data) = self {
             bytemuck::try_from_bytes(&data)
-                .map(|&x| x)
+                .copied()
                 .map_err(|_| SwitchboardError::AccountDeserializationError)
         } else {
             Err(SwitchboardError::AccountDeserializationError)
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runway() {
+        let lease = LeaseAccountData {
+            is_active: true,
+            ..Default::default()
+        };
+        let mut queue = OracleQueueAccountData {
+            reward: 10,
+            ..Default::default()
+        };
+
+        let runway = lease.runway(1_005, &queue, 4, 30).unwrap();
+        assert_eq!(
+            runway,
+            LeaseRunway {
+                is_active: true,
+                cost_per_update: queue.max_round_rewards(4),
+                remaining_updates: Some(20),
+                seconds_remaining: Some(600),
+            }
+        );
+        assert_eq!(runway.cost_per_update, 50);
+        assert_eq!(runway.depleted_at(1_000), Some(1_600));
+
+        let runway = lease.runway(49, &queue, 4, 30).unwrap();
+        assert_eq!(runway.remaining_updates, Some(0));
+        assert_eq!(runway.depleted_at(1_000), Some(1_000));
+
+        // without a min update delay a funded escrow can be spent at any rate
+        let runway = lease.runway(1_005, &queue, 4, 0).unwrap();
+        assert_eq!(runway.remaining_updates, Some(20));
+        assert_eq!(runway.seconds_remaining, None);
+        assert_eq!(runway.depleted_at(1_000), None);
+        let runway = lease.runway(49, &queue, 4, 0).unwrap();
+        assert_eq!(runway.seconds_remaining, Some(0));
+
+        queue.reward = 0;
+        let runway = lease.runway(1_005, &queue, 4, 30).unwrap();
+        assert_eq!(runway.remaining_updates, None);
+        assert_eq!(runway.depleted_at(1_000), None);
+
+        // an inactive lease funds nothing, whatever its balance or the queue's reward
+        let inactive = LeaseAccountData::default();
+        for reward in [0, 10] {
+            queue.reward = reward;
+            let runway = inactive.runway(1_005, &queue, 4, 30).unwrap();
+            assert!(!runway.is_active);
+            assert_eq!(runway.remaining_updates, Some(0));
+            assert_eq!(runway.seconds_remaining, Some(0));
+            assert_eq!(runway.depleted_at(1_000), Some(1_000));
+        }
+
+        queue.reward = u64::MAX;
+        assert_eq!(
+            lease.runway(1_005, &queue, 4, 30).unwrap_err(),
+            SwitchboardError::IntegerOverflowError.into()
+        );
+        assert_eq!(
+            lease.runway(1_005, &queue, u32::MAX, 30).unwrap_err(),
+            SwitchboardError::IntegerOverflowError.into()
+        );
+    }
+}
@@ -1,18 +1,25 @@
 pub mod aggregator;
 pub mod buffer_relayer;
 pub mod crank;
+pub mod ecvrf;
 pub mod history_buffer;
 pub mod job;
+#[cfg(test)]
+mod layout;
 pub mod lease;
 pub mod oracle;
 pub mod permission;
 pub mod queue;
 pub mod sb_state;
 pub mod sliding_window;
+pub mod vrf;
+pub mod vrf_lite;
+pub mod vrf_pool;
 
 pub use aggregator::*;
 pub use buffer_relayer::*;
 pub use crank::*;
+pub use ecvrf::*;
 pub use history_buffer::*;
 pub use job::*;
 pub use lease::*;
@@ -21,3 +28,6 @@ pub use permission::*;
 pub use queue::*;
 pub use sb_state::*;
 pub use sliding_window::*;
+pub use vrf::*;
+pub use vrf_lite::*;
+pub use vrf_pool::*;
@@ -1,5 +1,7 @@
 use crate::prelude::*;
 use std::cell::Ref;
+pub use switchboard_core::types::OracleMetrics;
+use switchboard_core::{decode, layout};
 
 #[derive(Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub enum OracleResponseType {
@@ -9,32 +11,8 @@ pub enum OracleResponseType {
     TypeNoResponse,
 }
 
-#[zero_copy(unsafe)]
-#[derive(Default)]
-#[repr(packed)]
-pub struct OracleMetrics {
-    /// Number of consecutive successful update request.
-    pub consecutive_success: u64,
-    /// Number of consecutive update request that resulted in an error.
-    pub consecutive_error: u64,
-    /// Number of consecutive update request that resulted in a disagreement with the accepted median result.
-    pub consecutive_disagreement: u64,
-    /// Number of consecutive update request that were posted on-chain late and not included in an accepted result.
-    pub consecutive_late_response: u64,
-    /// Number of consecutive update request that resulted in a failure.
-    pub consecutive_failure: u64,
-    /// Total number of successful update request.
-    pub total_success: u128,
-    /// Total number of update request that resulted in an error.
-    pub total_error: u128,
-    /// Total number of update request that resulted in a disagreement with the accepted median result.
-    pub total_disagreement: u128,
-    /// Total number of update request that were posted on-chain late and not included in an accepted result.
-    pub total_late_response: u128,
-}
-
 #[account(zero_copy(unsafe))]
-#[repr(packed)]
+#[repr(C, packed)]
 pub struct OracleAccountData {
     /// Name of the oracle to store on-chain.
     pub name: [u8; 32],
@@ -58,6 +36,11 @@ pub struct OracleAccountData {
     /// Reserved for future info.
     pub _ebuf: [u8; 255],
 }
+impl Default for OracleAccountData {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
 
 impl OracleAccountData {
     pub fn size() -> usize {
@@ -80,16 +63,11 @@ impl OracleAccountData {
     pub fn new<'info>(
         account_info: &'info AccountInfo<'info>,
     ) -> anchor_lang::Result<Ref<'info, Self>> {
-        let data = account_info.try_borrow_data()?;
-        if data.len() < OracleAccountData::discriminator().len() {
-            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
-        }
-
-        let mut disc_bytes = [0u8; 8];
-        disc_bytes.copy_from_slice(&data[..8]);
-        if disc_bytes != OracleAccountData::discriminator() {
-            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        if *account_info.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
         }
+        let data = account_info.try_borrow_data()?;
+        decode::account_data(&data, &layout::ORACLE_ACCOUNT_DATA).map_err(decode_error)?;
 
         Ok(Ref::map(data, |data| {
             bytemuck::from_bytes(&data[8..std::mem::size_of::<OracleAccountData>() + 8])
@@ -110,18 +88,203 @@ impl OracleAccountData {
     /// let oracle = OracleAccountData::new(oracle_account_info.try_borrow_data()?)?;
     /// ```
     pub fn new_from_bytes(data: &[u8]) -> anchor_lang::Result<&OracleAccountData> {
-        if data.len() < OracleAccountData::discriminator().len() {
-            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
-        }
-
-        let mut disc_bytes = [0u8; 8];
-        disc_bytes.copy_from_slice(&data[..8]);
-        if disc_bytes != OracleAccountData::discriminator() {
-            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
-        }
+        decode::account_data(data, &layout::ORACLE_ACCOUNT_DATA).map_err(decode_error)?;
 
         Ok(bytemuck::from_bytes(
             &data[8..std::mem::size_of::<OracleAccountData>() + 8],
         ))
     }
+
+    /// Returns a health report for the oracle, judged against the eviction rules of the queue it
+    /// heartbeats on.
+    ///
+    /// # Arguments
+    ///
+    /// * `queue` - The oracle queue the oracle belongs to
+    /// * `now` - The current unix timestamp
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{OracleAccountData, OracleHealthGrade, OracleQueueAccountData};
+    ///
+    /// let oracle = OracleAccountData::new_from_bytes(&oracle_account.data)?;
+    /// let queue = OracleQueueAccountData::new_from_bytes(&queue_account.data)?;
+    /// if oracle.health(queue, now).grade >= OracleHealthGrade::Critical {
+    ///     alert(&oracle_pubkey);
+    /// }
+    /// ```
+    pub fn health(&self, queue: &OracleQueueAccountData, now: i64) -> OracleHealth {
+        let metrics = self.metrics;
+        let heartbeat_deadline =
+            { self.last_heartbeat }.saturating_add(queue.oracle_timeout.into());
+        let is_live = now <= heartbeat_deadline;
+        let heartbeat_age = now.saturating_sub(self.last_heartbeat);
+
+        let failure_limit = queue.consecutive_oracle_failure_limit;
+        let consecutive_failure = metrics.consecutive_failure;
+        let failures_until_revocation =
+            (failure_limit != 0).then(|| failure_limit.saturating_sub(consecutive_failure));
+
+        let total_responses = { metrics.total_success }
+            .saturating_add(metrics.total_error)
+            .saturating_add(metrics.total_disagreement)
+            .saturating_add(metrics.total_late_response);
+        let ratio_bps = |count: u128| {
+            (total_responses != 0).then(|| {
+                // count <= total_responses, so the ratio fits in 10_000
+                (count.saturating_mul(10_000) / total_responses) as u32
+            })
+        };
+
+        let grade = if !is_live || failures_until_revocation == Some(0) {
+            OracleHealthGrade::Offline
+        } else if failure_limit != 0 && consecutive_failure.saturating_mul(2) >= failure_limit {
+            OracleHealthGrade::Critical
+        } else if consecutive_failure != 0
+            || metrics.consecutive_error != 0
+            || metrics.consecutive_disagreement != 0
+            || metrics.consecutive_late_response != 0
+            || heartbeat_age > i64::from(queue.oracle_timeout / 2)
+        {
+            OracleHealthGrade::Degraded
+        } else {
+            OracleHealthGrade::Healthy
+        };
+
+        OracleHealth {
+            is_live,
+            heartbeat_deadline,
+            success_ratio_bps: ratio_bps(metrics.total_success),
+            disagreement_ratio_bps: ratio_bps(metrics.total_disagreement),
+            failures_until_revocation,
+            grade,
+        }
+    }
+}
+
+/// The overall health of an oracle, ordered from best to worst.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OracleHealthGrade {
+    /// The oracle is live with no failure, error, disagreement or late response streak.
+    Healthy,
+    /// The oracle has an active failure, error, disagreement or late response streak, or is past
+    /// half of the queue's oracle_timeout since its last heartbeat.
+    Degraded,
+    /// The oracle is at least halfway to the queue's consecutive_oracle_failure_limit.
+    Critical,
+    /// The oracle missed the queue's oracle_timeout and can be removed from the queue, or reached
+    /// the consecutive_oracle_failure_limit and has its permission revoked.
+    Offline,
+}
+
+/// A health report derived from an oracle's metrics and last heartbeat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OracleHealth {
+    /// Whether the oracle heartbeated within the queue's oracle_timeout.
+    pub is_live: bool,
+    /// The unix timestamp after which the oracle can be removed from the queue if it does not heartbeat.
+    pub heartbeat_deadline: i64,
+    /// The share of all recorded responses that succeeded, in basis points. None if the oracle
+    /// has not responded yet.
+    pub success_ratio_bps: Option<u32>,
+    /// The share of all recorded responses that disagreed with the accepted result, in basis
+    /// points. None if the oracle has not responded yet.
+    pub disagreement_ratio_bps: Option<u32>,
+    /// The number of further consecutive failures before the queue revokes the oracle's
+    /// permission. None if the queue has no failure limit.
+    pub failures_until_revocation: Option<u64>,
+    /// The overall health of the oracle.
+    pub grade: OracleHealthGrade,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue(oracle_timeout: u32, consecutive_oracle_failure_limit: u64) -> OracleQueueAccountData {
+        OracleQueueAccountData {
+            oracle_timeout,
+            consecutive_oracle_failure_limit,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_health() {
+        let mut oracle = OracleAccountData {
+            last_heartbeat: 1000,
+            metrics: OracleMetrics {
+                total_success: 75,
+                total_error: 5,
+                total_disagreement: 15,
+                total_late_response: 5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let queue = queue(180, 10);
+
+        let health = oracle.health(&queue, 1050);
+        assert_eq!(
+            health,
+            OracleHealth {
+                is_live: true,
+                heartbeat_deadline: 1180,
+                success_ratio_bps: Some(7500),
+                disagreement_ratio_bps: Some(1500),
+                failures_until_revocation: Some(10),
+                grade: OracleHealthGrade::Healthy,
+            }
+        );
+
+        // past half of the oracle timeout
+        assert_eq!(
+            oracle.health(&queue, 1091).grade,
+            OracleHealthGrade::Degraded
+        );
+        assert_eq!(
+            oracle.health(&queue, 1180).grade,
+            OracleHealthGrade::Degraded
+        );
+        assert_eq!(
+            oracle.health(&queue, 1181).grade,
+            OracleHealthGrade::Offline
+        );
+        assert!(!oracle.health(&queue, 1181).is_live);
+
+        oracle.metrics.consecutive_disagreement = 1;
+        assert_eq!(
+            oracle.health(&queue, 1050).grade,
+            OracleHealthGrade::Degraded
+        );
+
+        oracle.metrics.consecutive_failure = 5;
+        let health = oracle.health(&queue, 1050);
+        assert_eq!(health.grade, OracleHealthGrade::Critical);
+        assert_eq!(health.failures_until_revocation, Some(5));
+
+        oracle.metrics.consecutive_failure = 10;
+        let health = oracle.health(&queue, 1050);
+        assert_eq!(health.grade, OracleHealthGrade::Offline);
+        assert_eq!(health.failures_until_revocation, Some(0));
+    }
+
+    #[test]
+    fn test_health_without_limits() {
+        let oracle = OracleAccountData {
+            last_heartbeat: 1000,
+            metrics: OracleMetrics {
+                consecutive_failure: 50,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let health = oracle.health(&queue(180, 0), 1000);
+        assert_eq!(health.failures_until_revocation, None);
+        assert_eq!(health.success_ratio_bps, None);
+        assert_eq!(health.grade, OracleHealthGrade::Degraded);
+        assert!(OracleHealthGrade::Offline > OracleHealthGrade::Critical);
+    }
 }
@@ -11,7 +11,7 @@ pub enum SwitchboardPermission {
 }
 
 #[account(zero_copy(unsafe))]
-#[repr(packed)]
+#[repr(C, packed)]
 pub struct PermissionAccountData {
     /// The authority that is allowed to set permissions for this account.
     pub authority: Pubkey,
@@ -1,10 +1,11 @@
 use crate::prelude::*;
 use crate::TOKEN_PID;
-use bytemuck::try_cast_slice_mut;
+use bytemuck::{try_cast_slice, try_cast_slice_mut};
 use std::cell::Ref;
+use switchboard_core::{decode, layout, DecodeError};
 
 #[account(zero_copy(unsafe))]
-#[repr(packed)]
+#[repr(C, packed)]
 pub struct OracleQueueAccountData {
     /// Name of the queue to store on-chain.
     pub name: [u8; 32],
@@ -89,9 +90,16 @@ impl OracleQueueAccountData {
     }
 
     pub fn max_round_rewards(&self, batch_size: u32) -> u64 {
-        self.reward
-            .checked_mul(batch_size.checked_add(1).unwrap().into())
-            .unwrap()
+        self.checked_max_round_rewards(batch_size).unwrap()
+    }
+
+    /// Returns the most a lease pays for one update round, the reward for every oracle in the
+    /// batch plus the round opener. Errors if the total overflows a u64.
+    pub fn checked_max_round_rewards(&self, batch_size: u32) -> anchor_lang::Result<u64> {
+        batch_size
+            .checked_add(1)
+            .and_then(|recipients| self.reward.checked_mul(recipients.into()))
+            .ok_or(error!(SwitchboardError::IntegerOverflowError))
     }
 
     /// Returns the deserialized Switchboard OracleQueue account
@@ -110,16 +118,11 @@ impl OracleQueueAccountData {
     pub fn new<'info>(
         account_info: &'info AccountInfo<'info>,
     ) -> anchor_lang::Result<Ref<'info, Self>> {
-        let data = account_info.try_borrow_data()?;
-        if data.len() < OracleQueueAccountData::discriminator().len() {
-            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
-        }
-
-        let mut disc_bytes = [0u8; 8];
-        disc_bytes.copy_from_slice(&data[..8]);
-        if disc_bytes != OracleQueueAccountData::discriminator() {
-            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        if *account_info.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
         }
+        let data = account_info.try_borrow_data()?;
+        decode::account_data(&data, &layout::ORACLE_QUEUE_ACCOUNT_DATA).map_err(decode_error)?;
 
         Ok(Ref::map(data, |data| {
             bytemuck::from_bytes(&data[8..std::mem::size_of::<OracleQueueAccountData>() + 8])
@@ -140,18 +143,198 @@ impl OracleQueueAccountData {
     /// let oracle_queue = OracleQueueAccountData::new(oracle_account_info.try_borrow_data()?)?;
     /// ```
     pub fn new_from_bytes(data: &[u8]) -> anchor_lang::Result<&OracleQueueAccountData> {
-        if data.len() < OracleQueueAccountData::discriminator().len() {
-            return Err(ErrorCode::AccountDiscriminatorNotFound.into());
-        }
-
-        let mut disc_bytes = [0u8; 8];
-        disc_bytes.copy_from_slice(&data[..8]);
-        if disc_bytes != OracleQueueAccountData::discriminator() {
-            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
-        }
+        decode::account_data(data, &layout::ORACLE_QUEUE_ACCOUNT_DATA).map_err(decode_error)?;
 
         Ok(bytemuck::from_bytes(
             &data[8..std::mem::size_of::<OracleQueueAccountData>() + 8],
         ))
     }
+
+    /// Returns the oracles currently on the queue, the first `size` entries of its data buffer
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer_info` - A Solana AccountInfo referencing the queue's data buffer
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::OracleQueueAccountData;
+    ///
+    /// let oracle_queue = OracleQueueAccountData::new(queue_account_info)?;
+    /// let oracles = oracle_queue.load_oracles(data_buffer_account_info)?;
+    /// ```
+    pub fn load_oracles<'info>(
+        &self,
+        buffer_info: &'info AccountInfo<'info>,
+    ) -> anchor_lang::Result<Ref<'info, [Pubkey]>> {
+        if *buffer_info.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
+        }
+        if *buffer_info.key != self.data_buffer {
+            return Err(error!(SwitchboardError::InvalidQueueDataBuffer));
+        }
+        let data = buffer_info.try_borrow_data()?;
+        let oracles_len = self.oracles_from_bytes(&data)?.len();
+
+        Ref::filter_map(data, |data| {
+            try_cast_slice(&data[8..8 + oracles_len * 32]).ok()
+        })
+        .map_err(|_| error!(SwitchboardError::AccountDeserializationError))
+    }
+
+    /// Returns the oracles currently on the queue from the raw data of its data buffer. Useful
+    /// for monitoring queues off-chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The queue's data buffer account data
+    pub fn oracles_from_bytes<'a>(&self, data: &'a [u8]) -> anchor_lang::Result<&'a [Pubkey]> {
+        if self.size > self.max_size {
+            return Err(error!(SwitchboardError::AccountDeserializationError));
+        }
+        let oracles = decode::queue_buffer(data, self.size as usize).map_err(|err| match err {
+            DecodeError::DiscriminatorMismatch => {
+                SwitchboardError::AccountDiscriminatorMismatch.into()
+            }
+            err => decode_error(err),
+        })?;
+
+        try_cast_slice(oracles).map_err(|_| error!(SwitchboardError::AccountDeserializationError))
+    }
+
+    /// Returns the oracles in the order the queue assigns them to update requests, starting at
+    /// `curr_idx` and wrapping around.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracles` - The oracles currently on the queue
+    pub fn rotation<'a>(&self, oracles: &'a [Pubkey]) -> impl Iterator<Item = Pubkey> + 'a {
+        let start = if oracles.is_empty() {
+            0
+        } else {
+            self.curr_idx as usize % oracles.len()
+        };
+        oracles[start..]
+            .iter()
+            .chain(oracles[..start].iter())
+            .copied()
+    }
+
+    /// Returns the oracles that would be assigned to the next update request of the given batch
+    /// size. A batch is never larger than the number of oracles on the queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracles` - The oracles currently on the queue
+    /// * `batch_size` - The number of oracles the request needs
+    pub fn next_batch(&self, oracles: &[Pubkey], batch_size: u32) -> Vec<Pubkey> {
+        self.rotation(oracles).take(batch_size as usize).collect()
+    }
+
+    /// Returns the oracles that would be assigned to the aggregator's next round.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracles` - The oracles currently on the queue
+    /// * `aggregator` - The aggregator opening the round
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::{AggregatorAccountData, OracleQueueAccountData};
+    ///
+    /// let oracle_queue = OracleQueueAccountData::new_from_bytes(&queue_account.data)?;
+    /// let oracles = oracle_queue.oracles_from_bytes(&data_buffer_account.data)?;
+    /// let aggregator = AggregatorAccountData::new_from_bytes(&aggregator_account.data)?;
+    /// let assigned = oracle_queue.next_aggregator_oracles(oracles, aggregator);
+    /// ```
+    pub fn next_aggregator_oracles(
+        &self,
+        oracles: &[Pubkey],
+        aggregator: &AggregatorAccountData,
+    ) -> Vec<Pubkey> {
+        self.next_batch(oracles, aggregator.oracle_request_batch_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BUFFER_DISCRIMINATOR;
+
+    fn oracle(i: u8) -> Pubkey {
+        Pubkey::new_from_array([i; 32])
+    }
+
+    fn buffer_data(max_size: u8) -> Vec<u8> {
+        let mut data = BUFFER_DISCRIMINATOR.to_vec();
+        for i in 1..=max_size {
+            data.extend_from_slice(oracle(i).as_ref());
+        }
+        data
+    }
+
+    #[test]
+    fn test_oracles_from_bytes() {
+        let mut queue = OracleQueueAccountData {
+            size: 3,
+            max_size: 6,
+            ..Default::default()
+        };
+        let data = buffer_data(5);
+        assert_eq!(
+            queue.oracles_from_bytes(&data).unwrap(),
+            &[oracle(1), oracle(2), oracle(3)]
+        );
+
+        queue.size = 6;
+        assert_eq!(
+            queue.oracles_from_bytes(&data).unwrap_err(),
+            SwitchboardError::AccountDeserializationError.into()
+        );
+        // a size beyond the queue's capacity is rejected even if the buffer is long enough
+        queue.size = 4;
+        queue.max_size = 3;
+        assert_eq!(
+            queue.oracles_from_bytes(&data).unwrap_err(),
+            SwitchboardError::AccountDeserializationError.into()
+        );
+        queue.max_size = 6;
+        queue.size = 3;
+        assert_eq!(
+            queue.oracles_from_bytes(&data[8..]).unwrap_err(),
+            SwitchboardError::AccountDiscriminatorMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_rotation() {
+        let queue = OracleQueueAccountData {
+            size: 4,
+            max_size: 4,
+            curr_idx: 6,
+            ..Default::default()
+        };
+        let data = buffer_data(4);
+        let oracles = queue.oracles_from_bytes(&data).unwrap();
+
+        let rotation: Vec<Pubkey> = queue.rotation(oracles).collect();
+        assert_eq!(rotation, vec![oracle(3), oracle(4), oracle(1), oracle(2)]);
+        assert_eq!(
+            queue.next_batch(oracles, 3),
+            vec![oracle(3), oracle(4), oracle(1)]
+        );
+        assert_eq!(queue.next_batch(oracles, 10).len(), 4);
+
+        let aggregator = AggregatorAccountData {
+            oracle_request_batch_size: 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            queue.next_aggregator_oracles(oracles, &aggregator),
+            vec![oracle(3), oracle(4)]
+        );
+        assert!(queue.next_batch(&[], 2).is_empty());
+    }
 }
@@ -1,7 +1,7 @@
 use crate::prelude::*;
 
 #[account(zero_copy(unsafe))]
-#[repr(packed)]
+#[repr(C, packed)]
 pub struct SbState {
     /// The account authority permitted to make account changes.
     pub authority: Pubkey,
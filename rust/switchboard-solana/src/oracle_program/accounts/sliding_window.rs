@@ -1,8 +1,11 @@
 use crate::prelude::*;
+use rust_decimal::Decimal;
+use std::cell::Ref;
+use switchboard_core::{decode, layout};
 
 #[zero_copy(unsafe)]
 #[derive(Default)]
-#[repr(packed)]
+#[repr(C, packed)]
 pub struct SlidingWindowElement {
     pub oracle_key: Pubkey,
     pub value: SwitchboardDecimal,
@@ -10,16 +13,288 @@ pub struct SlidingWindowElement {
     pub timestamp: i64,
 }
 
+impl SlidingWindowElement {
+    /// Returns true if no oracle has reported into this slot of the window.
+    pub fn is_empty(&self) -> bool {
+        self.oracle_key == Pubkey::default()
+    }
+}
+
 #[account(zero_copy(unsafe))]
-#[repr(packed)]
+#[repr(C, packed)]
 pub struct SlidingResultAccountData {
     pub data: [SlidingWindowElement; 16],
     pub bump: u8,
     pub _ebuf: [u8; 512],
 }
 
+impl Default for SlidingResultAccountData {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
 impl SlidingResultAccountData {
     pub fn size() -> usize {
         8 + std::mem::size_of::<SlidingResultAccountData>()
     }
+
+    /// Returns the PDA and bump of the sliding result account for a given aggregator.
+    pub fn find_program_address(aggregator: &Pubkey) -> (Pubkey, u8) {
+        crate::pda::find_sliding_result(aggregator)
+    }
+
+    /// Returns the deserialized Switchboard SlidingResult account
+    ///
+    /// # Arguments
+    ///
+    /// * `account_info` - A Solana AccountInfo referencing an existing Switchboard SlidingResult account
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::SlidingResultAccountData;
+    ///
+    /// let sliding_result = SlidingResultAccountData::new(sliding_result_account_info)?;
+    /// ```
+    pub fn new<'info>(
+        account_info: &'info AccountInfo<'info>,
+    ) -> anchor_lang::Result<Ref<'info, Self>> {
+        if *account_info.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
+        }
+        let data = account_info.try_borrow_data()?;
+        Self::check_discriminator(&data)?;
+
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..std::mem::size_of::<SlidingResultAccountData>() + 8])
+        }))
+    }
+
+    /// Returns the deserialized Switchboard SlidingResult account
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A Solana AccountInfo's data buffer
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::SlidingResultAccountData;
+    ///
+    /// let sliding_result = SlidingResultAccountData::new_from_bytes(sliding_result_account_info.try_borrow_data()?)?;
+    /// ```
+    pub fn new_from_bytes(data: &[u8]) -> anchor_lang::Result<&SlidingResultAccountData> {
+        Self::check_discriminator(data)?;
+
+        Ok(bytemuck::from_bytes(
+            &data[8..std::mem::size_of::<SlidingResultAccountData>() + 8],
+        ))
+    }
+
+    /// Verify the account data starts with the SlidingResultAccountData discriminator and is
+    /// large enough to hold the account.
+    pub fn check_discriminator(data: &[u8]) -> anchor_lang::Result<()> {
+        decode::account_data(data, &layout::SLIDING_RESULT_ACCOUNT_DATA).map_err(decode_error)?;
+        Ok(())
+    }
+
+    /// Returns the populated elements reported at or after the horizon.
+    ///
+    /// # Arguments
+    ///
+    /// * `horizon` - The unix timestamp before which elements are ignored.
+    pub fn fresh_elements(&self, horizon: i64) -> Vec<SlidingWindowElement> {
+        let data = self.data;
+        data.into_iter()
+            .filter(|element| !element.is_empty() && element.timestamp >= horizon)
+            .collect()
+    }
+
+    /// Returns the number of distinct oracles with an element reported at or after the horizon.
+    pub fn fresh_oracle_count(&self, horizon: i64) -> u32 {
+        let mut oracles: Vec<Pubkey> = self
+            .fresh_elements(horizon)
+            .into_iter()
+            .map(|element| element.oracle_key)
+            .collect();
+        oracles.sort();
+        oracles.dedup();
+        oracles.len() as u32
+    }
+
+    /// Returns the median value of the elements reported at or after the horizon. Even sized
+    /// windows return the mean of the two middle values.
+    pub fn median(&self, horizon: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        let mut values = self.fresh_values(horizon)?;
+        if values.is_empty() {
+            return Err(SwitchboardError::InvalidAggregatorRound.into());
+        }
+        values.sort();
+
+        let mid = values.len() / 2;
+        if values.len() % 2 == 1 {
+            return Ok(values[mid].into());
+        }
+        let median = values[mid - 1]
+            .checked_add(values[mid])
+            .and_then(|sum| sum.checked_div(Decimal::TWO))
+            .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+        Ok(median.into())
+    }
+
+    /// Returns the weighted median value of the elements reported at or after the horizon,
+    /// the smallest value at which the cumulative weight reaches half of the total weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `horizon` - The unix timestamp before which elements are ignored.
+    /// * `weight` - Returns the weight of an element. Elements with a weight of zero are ignored.
+    pub fn weighted_median<F>(
+        &self,
+        horizon: i64,
+        weight: F,
+    ) -> anchor_lang::Result<SwitchboardDecimal>
+    where
+        F: Fn(&SlidingWindowElement) -> u64,
+    {
+        let mut weighted: Vec<(Decimal, u128)> = Vec::with_capacity(16);
+        for element in self.fresh_elements(horizon) {
+            let w = weight(&element);
+            if w == 0 {
+                continue;
+            }
+            weighted.push((element.value.try_into()?, w.into()));
+        }
+        if weighted.is_empty() {
+            return Err(SwitchboardError::InvalidAggregatorRound.into());
+        }
+        weighted.sort_by_key(|(value, _)| *value);
+
+        let total: u128 = weighted.iter().map(|(_, w)| w).sum();
+        let mut cumulative: u128 = 0;
+        for (value, w) in weighted.iter() {
+            cumulative += w;
+            if cumulative * 2 >= total {
+                return Ok((*value).into());
+            }
+        }
+        Err(SwitchboardError::InvalidAggregatorRound.into())
+    }
+
+    /// Returns the difference between the max and min value of the elements reported at or
+    /// after the horizon.
+    pub fn spread(&self, horizon: i64) -> anchor_lang::Result<SwitchboardDecimal> {
+        let values = self.fresh_values(horizon)?;
+        let (min, max) = match (values.iter().min(), values.iter().max()) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return Err(SwitchboardError::InvalidAggregatorRound.into()),
+        };
+        let spread = max
+            .checked_sub(*min)
+            .ok_or(error!(SwitchboardError::IntegerOverflowError))?;
+        Ok(spread.into())
+    }
+
+    /// Returns the number of seconds since the most recent element was reported, or None if
+    /// no oracle has reported.
+    pub fn freshest_age(&self, unix_timestamp: i64) -> Option<i64> {
+        let data = self.data;
+        data.into_iter()
+            .filter(|element| !element.is_empty())
+            .map(|element| element.timestamp)
+            .max()
+            .map(|timestamp| unix_timestamp.saturating_sub(timestamp))
+    }
+
+    fn fresh_values(&self, horizon: i64) -> anchor_lang::Result<Vec<Decimal>> {
+        self.fresh_elements(horizon)
+            .into_iter()
+            .map(|element| Ok(element.value.try_into()?))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_sliding_result(elements: &[(u8, i128, i64)]) -> SlidingResultAccountData {
+        let mut sliding_result = SlidingResultAccountData::default();
+        for (i, (oracle, value, timestamp)) in elements.iter().enumerate() {
+            sliding_result.data[i] = SlidingWindowElement {
+                oracle_key: Pubkey::new_from_array([*oracle; 32]),
+                value: SwitchboardDecimal::new(*value, 2),
+                slot: 0,
+                timestamp: *timestamp,
+            };
+        }
+        sliding_result
+    }
+
+    #[test]
+    fn test_new_from_bytes() {
+        let sliding_result = create_sliding_result(&[(1, 10_000, 100)]);
+        let mut data = SlidingResultAccountData::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&sliding_result));
+
+        let loaded = SlidingResultAccountData::new_from_bytes(&data).unwrap();
+        assert_eq!({ loaded.data[0].value }, SwitchboardDecimal::new(10_000, 2));
+
+        assert!(SlidingResultAccountData::new_from_bytes(&data[..100]).is_err());
+        data[0] = 0;
+        assert!(SlidingResultAccountData::new_from_bytes(&data).is_err());
+    }
+
+    #[test]
+    fn test_median_ignores_stale_elements() {
+        let sliding_result = create_sliding_result(&[
+            (1, 10_000, 100),
+            (2, 10_200, 110),
+            (3, 10_100, 120),
+            (4, 50_000, 10),
+        ]);
+        assert_eq!(
+            sliding_result.median(100).unwrap(),
+            SwitchboardDecimal::new(10_100, 2)
+        );
+        // (101.00 + 200.00) / 2 including the stale element
+        let median: Decimal = sliding_result.median(0).unwrap().try_into().unwrap();
+        assert_eq!(median, Decimal::new(10_150, 2));
+        assert!(sliding_result.median(1_000).is_err());
+    }
+
+    #[test]
+    fn test_weighted_median() {
+        let sliding_result =
+            create_sliding_result(&[(1, 10_000, 100), (2, 10_200, 110), (3, 10_100, 120)]);
+        let median = sliding_result
+            .weighted_median(0, |element| if element.timestamp == 110 { 3 } else { 1 })
+            .unwrap();
+        assert_eq!(median, SwitchboardDecimal::new(10_200, 2));
+        assert!(sliding_result.weighted_median(0, |_| 0).is_err());
+    }
+
+    #[test]
+    fn test_spread_and_freshest_age() {
+        let sliding_result =
+            create_sliding_result(&[(1, 10_000, 100), (2, 10_200, 110), (3, 9_000, 50)]);
+        let spread: Decimal = sliding_result.spread(100).unwrap().try_into().unwrap();
+        assert_eq!(spread, Decimal::new(200, 2));
+        assert_eq!(sliding_result.freshest_age(130), Some(20));
+        assert_eq!(SlidingResultAccountData::default().freshest_age(130), None);
+    }
+
+    #[test]
+    fn test_fresh_oracle_count() {
+        let sliding_result = create_sliding_result(&[
+            (1, 10_000, 100),
+            (1, 10_100, 110),
+            (2, 10_200, 120),
+            (3, 10_300, 10),
+        ]);
+        assert_eq!(sliding_result.fresh_oracle_count(100), 2);
+        assert_eq!(sliding_result.fresh_oracle_count(0), 3);
+        assert_eq!(sliding_result.fresh_oracle_count(1_000), 0);
+    }
 }
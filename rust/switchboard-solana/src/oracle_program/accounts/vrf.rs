@@ -0,0 +1,137 @@
+use crate::prelude::*;
+use std::cell::Ref;
+use switchboard_core::{decode, layout};
+
+#[account(zero_copy(unsafe))]
+#[repr(C, packed)]
+pub struct VrfAccountData {
+    /// The current status of the VRF account as a raw [`VrfStatus`], see [`Self::status`].
+    pub status: u8,
+    /// Incremental counter for tracking VRF rounds.
+    pub counter: u128,
+    /// On-chain account delegated for making account changes.
+    pub authority: Pubkey,
+    /// The OracleQueueAccountData that is assigned to fulfill VRF update request.
+    pub oracle_queue: Pubkey,
+    /// The token account used to hold funds for VRF update request.
+    pub escrow: Pubkey,
+    /// The callback that is invoked when an update request is successfully verified.
+    pub callback: CallbackZC,
+    /// The number of oracles assigned to a VRF update request.
+    pub batch_size: u32,
+    /// Struct containing the intermediate state between VRF crank actions.
+    pub builders: [VrfBuilder; 8],
+    /// The number of builders.
+    pub builders_len: u32,
+    pub test_mode: bool,
+    /// Oracle results from the current round of update request that has not been accepted as valid yet
+    pub current_round: VrfRound,
+    /// Reserved for future info.
+    pub _ebuf: [u8; 1024],
+}
+impl Default for VrfAccountData {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl VrfAccountData {
+    pub fn size() -> usize {
+        8 + std::mem::size_of::<VrfAccountData>()
+    }
+
+    /// Returns the deserialized Switchboard VRF account
+    ///
+    /// # Arguments
+    ///
+    /// * `account_info` - A Solana AccountInfo referencing an existing Switchboard VRF account
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfAccountData;
+    ///
+    /// let vrf = VrfAccountData::new(vrf_account_info)?;
+    /// ```
+    pub fn new<'info>(
+        account_info: &'info AccountInfo<'info>,
+    ) -> anchor_lang::Result<Ref<'info, Self>> {
+        if *account_info.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
+        }
+        let data = account_info.try_borrow_data()?;
+        decode::account_data(&data, &layout::VRF_ACCOUNT_DATA).map_err(decode_error)?;
+
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..std::mem::size_of::<VrfAccountData>() + 8])
+        }))
+    }
+
+    /// Returns the deserialized Switchboard VRF account
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A Solana AccountInfo's data buffer
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfAccountData;
+    ///
+    /// let vrf = VrfAccountData::new_from_bytes(vrf_account_info.try_borrow_data()?)?;
+    /// ```
+    pub fn new_from_bytes(data: &[u8]) -> anchor_lang::Result<&VrfAccountData> {
+        decode::account_data(data, &layout::VRF_ACCOUNT_DATA).map_err(decode_error)?;
+
+        Ok(bytemuck::from_bytes(
+            &data[8..std::mem::size_of::<VrfAccountData>() + 8],
+        ))
+    }
+
+    /// Returns the current status of the VRF account, or an error if the stored byte is not a
+    /// known status.
+    pub fn status(&self) -> anchor_lang::Result<VrfStatus> {
+        VrfStatus::try_from(self.status)
+    }
+
+    /// Returns the current VRF round ID
+    pub fn get_current_randomness_round_id(&self) -> u128 {
+        self.counter
+    }
+
+    /// If the current round has been verified, returns the latest on-chain randomness
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfAccountData;
+    ///
+    /// let result = VrfAccountData::new(vrf_account_info)?.get_result()?;
+    /// ```
+    pub fn get_result(&self) -> anchor_lang::Result<[u8; 32]> {
+        if self.current_round.result == [0u8; 32] {
+            return Err(error!(SwitchboardError::VrfEmptyError));
+        }
+        Ok(self.current_round.result)
+    }
+
+    /// Returns a summary of each builder assigned to the current round, in builder order.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfAccountData;
+    ///
+    /// let vrf = VrfAccountData::new(vrf_account_info)?;
+    /// for report in vrf.builder_reports().iter().filter(|report| report.is_pending()) {
+    ///     msg!("{} has {} verify txns remaining", report.producer, report.tx_remaining);
+    /// }
+    /// ```
+    pub fn builder_reports(&self) -> Vec<VrfBuilderReport> {
+        let builders_len = (self.builders_len as usize).min(self.builders.len());
+        self.builders[..builders_len]
+            .iter()
+            .map(|builder| builder.report())
+            .collect()
+    }
+}
@@ -0,0 +1,178 @@
+use crate::prelude::*;
+use std::cell::Ref;
+use switchboard_core::{decode, layout};
+
+#[account(zero_copy(unsafe))]
+#[repr(C, packed)]
+pub struct VrfLiteAccountData {
+    /// The bump used to derive the SbState account.
+    pub state_bump: u8,
+    /// The bump used to derive the permission account.
+    pub permission_bump: u8,
+    /// The VrfPool the account belongs to.
+    pub vrf_pool: Pubkey,
+    /// The current status of the VRF account as a raw [`VrfStatus`], see [`Self::status`].
+    pub status: u8,
+    /// The VRF round result. Will be zeroized if still awaiting fulfillment.
+    pub result: [u8; 32],
+    /// Incremental counter for tracking VRF rounds.
+    pub counter: u128,
+    /// The alpha bytes used to calculate the VRF proof.
+    pub alpha: [u8; 256],
+    /// The number of bytes in the alpha buffer.
+    pub alpha_len: u32,
+    /// The Slot when the VRF round was opened.
+    pub request_slot: u64,
+    /// The unix timestamp when the VRF round was opened.
+    pub request_timestamp: i64,
+    /// On-chain account delegated for making account changes.
+    pub authority: Pubkey,
+    /// The OracleQueueAccountData that is assigned to fulfill VRF update request.
+    pub queue: Pubkey,
+    /// The token account used to hold funds for VRF update request.
+    pub escrow: Pubkey,
+    /// The callback that is invoked when an update request is successfully verified.
+    pub callback: CallbackZC,
+    /// The incremental VRF proof calculation.
+    pub builder: VrfBuilder,
+    // unused currently. may want permission PDA per permission for
+    // unique expiration periods, BUT currently only one permission
+    // per account makes sense for the infra. Dont over engineer.
+    pub expiration: i64,
+}
+impl Default for VrfLiteAccountData {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl VrfLiteAccountData {
+    pub fn size() -> usize {
+        8 + std::mem::size_of::<VrfLiteAccountData>()
+    }
+
+    /// Returns the deserialized Switchboard VRF Lite account
+    ///
+    /// # Arguments
+    ///
+    /// * `account_info` - A Solana AccountInfo referencing an existing Switchboard VRF Lite account
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfLiteAccountData;
+    ///
+    /// let vrf_lite = VrfLiteAccountData::new(vrf_lite_account_info)?;
+    /// ```
+    pub fn new<'info>(
+        account_info: &'info AccountInfo<'info>,
+    ) -> anchor_lang::Result<Ref<'info, Self>> {
+        if *account_info.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
+        }
+        let data = account_info.try_borrow_data()?;
+        decode::account_data(&data, &layout::VRF_LITE_ACCOUNT_DATA).map_err(decode_error)?;
+
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..std::mem::size_of::<VrfLiteAccountData>() + 8])
+        }))
+    }
+
+    /// Returns the deserialized Switchboard VRF Lite account
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A Solana AccountInfo's data buffer
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfLiteAccountData;
+    ///
+    /// let vrf_lite = VrfLiteAccountData::new_from_bytes(vrf_lite_account_info.try_borrow_data()?)?;
+    /// ```
+    pub fn new_from_bytes(data: &[u8]) -> anchor_lang::Result<&VrfLiteAccountData> {
+        decode::account_data(data, &layout::VRF_LITE_ACCOUNT_DATA).map_err(decode_error)?;
+
+        Ok(bytemuck::from_bytes(
+            &data[8..std::mem::size_of::<VrfLiteAccountData>() + 8],
+        ))
+    }
+
+    /// Returns the current status of the VRF account, or an error if the stored byte is not a
+    /// known status.
+    pub fn status(&self) -> anchor_lang::Result<VrfStatus> {
+        VrfStatus::try_from(self.status)
+    }
+
+    /// Returns the current VRF round ID
+    pub fn get_current_randomness_round_id(&self) -> u128 {
+        self.counter
+    }
+
+    /// If the current round has been verified, returns the latest on-chain randomness
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfLiteAccountData;
+    ///
+    /// let result = VrfLiteAccountData::new(vrf_lite_account_info)?.get_result()?;
+    /// ```
+    pub fn get_result(&self) -> anchor_lang::Result<[u8; 32]> {
+        if self.result == [0u8; 32] {
+            return Err(error!(SwitchboardError::VrfEmptyError));
+        }
+        Ok(self.result)
+    }
+
+    /// Returns a summary of the builder verifying the current round.
+    pub fn builder_report(&self) -> VrfBuilderReport {
+        self.builder.report()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_from_bytes() {
+        let mut vrf_lite = VrfLiteAccountData {
+            status: VrfStatus::StatusCallbackSuccess as u8,
+            counter: 7,
+            ..Default::default()
+        };
+        let mut data = VrfLiteAccountData::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&vrf_lite));
+
+        let loaded = VrfLiteAccountData::new_from_bytes(&data).unwrap();
+        assert_eq!(loaded.status().unwrap(), VrfStatus::StatusCallbackSuccess);
+        assert_eq!(loaded.get_current_randomness_round_id(), 7);
+        assert_eq!(
+            loaded.get_result().unwrap_err(),
+            SwitchboardError::VrfEmptyError.into()
+        );
+
+        // an out of range status byte loads, but is rejected by the accessor
+        let status_offset = layout::VRF_LITE_ACCOUNT_DATA
+            .field("status")
+            .unwrap()
+            .offset;
+        data[8 + status_offset] = 6;
+        assert_eq!(
+            VrfLiteAccountData::new_from_bytes(&data)
+                .unwrap()
+                .status()
+                .unwrap_err(),
+            SwitchboardError::InvalidVrfStatus.into()
+        );
+
+        assert!(VrfLiteAccountData::new_from_bytes(&data[..100]).is_err());
+        data[0] = 0;
+        assert!(VrfLiteAccountData::new_from_bytes(&data).is_err());
+
+        vrf_lite.result = [1u8; 32];
+        assert_eq!(vrf_lite.get_result().unwrap(), [1u8; 32]);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::prelude::*;
+use bytemuck::try_cast_slice;
+use bytemuck::{Pod, Zeroable};
+use std::cell::Ref;
+use switchboard_core::{decode, layout};
+
+#[zero_copy(unsafe)]
+#[derive(Default, Debug)]
+#[repr(C, packed)]
+pub struct VrfPoolRow {
+    /// The unix timestamp when the VrfLite account was last used by the pool.
+    pub timestamp: i64,
+    /// The public key of the VrfLite account.
+    pub pubkey: Pubkey,
+}
+unsafe impl Pod for VrfPoolRow {}
+unsafe impl Zeroable for VrfPoolRow {}
+
+#[account(zero_copy(unsafe))]
+#[repr(C, packed)]
+pub struct VrfPoolAccountData {
+    /// The account delegated for making changes. Can never be changed or else the VrfLite accounts are unusable.
+    pub authority: Pubkey,
+    /// The OracleQueueAccountData that is assigned to fulfill VRF update request.
+    pub queue: Pubkey,
+    /// The token account used to fund requests for every VrfLite account in the pool.
+    pub escrow: Pubkey,
+    /// The minimum number of seconds between requests for a given VrfLite account.
+    pub min_interval: u32,
+    /// The maximum number of VrfLite accounts the pool can hold.
+    pub max_rows: u32,
+    /// The current number of VrfLite accounts in the pool.
+    pub size: u32,
+    /// The index of the next VrfLite account to request randomness.
+    pub idx: u32,
+    /// The bump used to derive the SbState account.
+    pub state_bump: u8,
+    /// Reserved for future info.
+    pub _ebuf: [u8; 135],
+}
+impl Default for VrfPoolAccountData {
+    fn default() -> Self {
+        unsafe { std::mem::zeroed() }
+    }
+}
+
+impl VrfPoolAccountData {
+    /// Returns the deserialized Switchboard VRF Pool account header
+    ///
+    /// # Arguments
+    ///
+    /// * `account_info` - A Solana AccountInfo referencing an existing Switchboard VRF Pool account
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfPoolAccountData;
+    ///
+    /// let vrf_pool = VrfPoolAccountData::new(vrf_pool_account_info)?;
+    /// ```
+    pub fn new<'info>(
+        account_info: &'info AccountInfo<'info>,
+    ) -> anchor_lang::Result<Ref<'info, Self>> {
+        if *account_info.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
+        }
+        let data = account_info.try_borrow_data()?;
+        decode::account_data(&data, &layout::VRF_POOL_ACCOUNT_DATA).map_err(decode_error)?;
+
+        Ok(Ref::map(data, |data| {
+            bytemuck::from_bytes(&data[8..std::mem::size_of::<VrfPoolAccountData>() + 8])
+        }))
+    }
+
+    /// Returns the deserialized Switchboard VRF Pool account header
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A Solana AccountInfo's data buffer
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfPoolAccountData;
+    ///
+    /// let vrf_pool = VrfPoolAccountData::new_from_bytes(vrf_pool_account_info.try_borrow_data()?)?;
+    /// ```
+    pub fn new_from_bytes(data: &[u8]) -> anchor_lang::Result<&VrfPoolAccountData> {
+        decode::account_data(data, &layout::VRF_POOL_ACCOUNT_DATA).map_err(decode_error)?;
+
+        Ok(bytemuck::from_bytes(
+            &data[8..std::mem::size_of::<VrfPoolAccountData>() + 8],
+        ))
+    }
+
+    /// Returns the populated rows of a Switchboard VRF Pool account, which trail the header
+    ///
+    /// # Arguments
+    ///
+    /// * `account_info` - A Solana AccountInfo referencing an existing Switchboard VRF Pool account
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfPoolAccountData;
+    ///
+    /// let rows = VrfPoolAccountData::load_rows(vrf_pool_account_info)?;
+    /// ```
+    pub fn load_rows<'info>(
+        account_info: &'info AccountInfo<'info>,
+    ) -> anchor_lang::Result<Ref<'info, [VrfPoolRow]>> {
+        if *account_info.owner != *SWITCHBOARD_PROGRAM_ID {
+            return Err(SwitchboardError::InvalidAccountOwner.into());
+        }
+        let data = account_info.try_borrow_data()?;
+        let rows_len = decode::vrf_pool_rows(&data).map_err(decode_error)?.len();
+        let start = layout::VRF_POOL_ACCOUNT_DATA.account_size();
+
+        Ref::filter_map(data, |data| {
+            try_cast_slice(&data[start..start + rows_len]).ok()
+        })
+        .map_err(|_| error!(SwitchboardError::AccountDeserializationError))
+    }
+}
+
+/// A read-only view over a VRF Pool account header and its populated rows.
+///
+/// The pool requests randomness from the row at `idx`, then advances `idx` round-robin. A
+/// request is rejected if the row was last used less than `min_interval` seconds ago.
+#[derive(Clone, Copy)]
+pub struct VrfPoolView<'a> {
+    /// The VRF Pool account header.
+    pub pool: &'a VrfPoolAccountData,
+    /// The populated rows of the pool.
+    pub rows: &'a [VrfPoolRow],
+}
+
+impl<'a> VrfPoolView<'a> {
+    /// Returns a view of the VRF Pool from a raw account data buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A Solana AccountInfo's data buffer
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use switchboard_solana::VrfPoolView;
+    ///
+    /// let account = rpc.get_account(&vrf_pool_pubkey)?;
+    /// let vrf_pool = VrfPoolView::new_from_bytes(&account.data)?;
+    /// let next_vrf = vrf_pool.next_row().map(|row| row.pubkey);
+    /// ```
+    pub fn new_from_bytes(data: &'a [u8]) -> anchor_lang::Result<VrfPoolView<'a>> {
+        let pool = VrfPoolAccountData::new_from_bytes(data)?;
+        let rows = decode::vrf_pool_rows(data).map_err(decode_error)?;
+        let rows: &[VrfPoolRow] = try_cast_slice(rows)
+            .map_err(|_| error!(SwitchboardError::AccountDeserializationError))?;
+
+        Ok(Self { pool, rows })
+    }
+
+    /// Returns the index of the row the next request will use, or None if the pool is empty.
+    pub fn next_index(&self) -> Option<usize> {
+        if self.rows.is_empty() {
+            return None;
+        }
+        Some(self.pool.idx as usize % self.rows.len())
+    }
+
+    /// Returns the row the next request will use, or None if the pool is empty.
+    pub fn next_row(&self) -> Option<VrfPoolRow> {
+        self.next_index().map(|idx| self.rows[idx])
+    }
+
+    /// Returns the populated rows in the order the pool will use them, starting with the next row.
+    pub fn rotation(&self) -> impl Iterator<Item = VrfPoolRow> + '_ {
+        let start = self.next_index().unwrap_or_default();
+        self.rows[start..]
+            .iter()
+            .chain(self.rows[..start].iter())
+            .copied()
+    }
+
+    /// Returns the unix timestamp at which the next row is no longer rejected by `min_interval`,
+    /// or None if the pool is empty.
+    pub fn next_request_timestamp(&self) -> Option<i64> {
+        let row = self.next_row()?;
+        Some({ row.timestamp }.saturating_add(self.pool.min_interval.into()))
+    }
+
+    /// Returns true if a request submitted at `now` would not be rejected by `min_interval`.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current unix timestamp
+    pub fn is_request_ready(&self, now: i64) -> bool {
+        self.next_request_timestamp()
+            .is_some_and(|timestamp| now >= timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool_data(min_interval: u32, idx: u32, timestamps: &[i64], max_rows: usize) -> Vec<u8> {
+        let pool = VrfPoolAccountData {
+            min_interval,
+            max_rows: max_rows as u32,
+            size: timestamps.len() as u32,
+            idx,
+            ..Default::default()
+        };
+        let mut data = VrfPoolAccountData::discriminator().to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&pool));
+        for (i, timestamp) in timestamps.iter().enumerate() {
+            let row = VrfPoolRow {
+                timestamp: *timestamp,
+                pubkey: Pubkey::new_from_array([i as u8 + 1; 32]),
+            };
+            data.extend_from_slice(bytemuck::bytes_of(&row));
+        }
+        data.resize(
+            layout::VRF_POOL_ACCOUNT_DATA.account_size() + max_rows * layout::VRF_POOL_ROW.size,
+            0,
+        );
+        data
+    }
+
+    #[test]
+    fn test_vrf_pool_view() {
+        let data = pool_data(60, 1, &[1000, 1030, 900], 5);
+        let vrf_pool = VrfPoolView::new_from_bytes(&data).unwrap();
+        assert_eq!(vrf_pool.rows.len(), 3);
+
+        assert_eq!(vrf_pool.next_index(), Some(1));
+        assert_eq!(
+            { vrf_pool.next_row().unwrap().pubkey },
+            Pubkey::new_from_array([2; 32])
+        );
+        let rotation: Vec<i64> = vrf_pool.rotation().map(|row| row.timestamp).collect();
+        assert_eq!(rotation, vec![1030, 900, 1000]);
+
+        assert_eq!(vrf_pool.next_request_timestamp(), Some(1090));
+        assert!(!vrf_pool.is_request_ready(1089));
+        assert!(vrf_pool.is_request_ready(1090));
+    }
+
+    #[test]
+    fn test_vrf_pool_view_edges() {
+        // idx wraps around the populated rows
+        let data = pool_data(0, 4, &[1, 2], 4);
+        let vrf_pool = VrfPoolView::new_from_bytes(&data).unwrap();
+        assert_eq!(vrf_pool.next_index(), Some(0));
+        assert!(vrf_pool.is_request_ready(2));
+
+        let data = pool_data(60, 0, &[], 4);
+        let vrf_pool = VrfPoolView::new_from_bytes(&data).unwrap();
+        assert_eq!(vrf_pool.next_row().map(|row| row.timestamp), None);
+        assert_eq!(vrf_pool.rotation().count(), 0);
+        assert!(!vrf_pool.is_request_ready(i64::MAX));
+
+        // size is larger than the rows the account holds
+        let mut data = pool_data(60, 0, &[1, 2], 2);
+        data.truncate(layout::VRF_POOL_ACCOUNT_DATA.account_size() + layout::VRF_POOL_ROW.size);
+        assert!(VrfPoolView::new_from_bytes(&data).is_err());
+    }
+}
@@ -78,8 +78,7 @@ impl<'info> AggregatorSaveResult {
                 is_writable: true,
             });
         }
-        let seeds = &[b"SlidingResultAccountData", self.aggregator.as_ref()];
-        let (sliding_window_key, _) = Pubkey::find_program_address(seeds, &SWITCHBOARD_PROGRAM_ID);
+        let (sliding_window_key, _) = crate::pda::find_sliding_result(&self.aggregator);
         remaining_accounts.push(AccountMeta {
             pubkey: sliding_window_key,
             is_signer: false,
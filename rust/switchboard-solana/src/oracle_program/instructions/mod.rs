@@ -1,7 +1,23 @@
 pub mod aggregator_save_result;
 pub mod oracle_heartbeat;
 pub mod permission_set;
+#[cfg(test)]
+mod test_utils;
+pub mod vrf_close;
+pub mod vrf_lite_close;
+pub mod vrf_lite_request_randomness;
+pub mod vrf_pool_remove;
+pub mod vrf_pool_request_randomness;
+pub mod vrf_request_randomness;
+pub mod vrf_set_callback;
 
 pub use aggregator_save_result::*;
 pub use oracle_heartbeat::*;
 pub use permission_set::*;
+pub use vrf_close::*;
+pub use vrf_lite_close::*;
+pub use vrf_lite_request_randomness::*;
+pub use vrf_pool_remove::*;
+pub use vrf_pool_request_randomness::*;
+pub use vrf_request_randomness::*;
+pub use vrf_set_callback::*;
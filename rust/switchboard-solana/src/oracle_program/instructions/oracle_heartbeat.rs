@@ -17,7 +17,7 @@ impl Discriminator for OracleHeartbeat {
     const DISCRIMINATOR: [u8; 8] = [10, 175, 217, 130, 111, 35, 117, 54];
 }
 
-impl<'info> OracleHeartbeat {
+impl OracleHeartbeat {
     #[allow(unused_variables)]
     pub fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
         vec![
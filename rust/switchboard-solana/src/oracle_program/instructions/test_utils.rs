@@ -0,0 +1,72 @@
+//! Account fixtures for the CPI instruction tests.
+
+use crate::prelude::*;
+use anchor_spl::token::spl_token::state::{Account as SplAccount, AccountState, Mint as SplMint};
+use anchor_spl::token::{Mint, TokenAccount};
+use solana_program::program_pack::Pack;
+
+/// Returns the anchor discriminator of the oracle program instruction with the given name.
+pub(crate) fn sighash(name: &str) -> [u8; 8] {
+    let hash = solana_program::hash::hash(format!("global:{}", name).as_bytes());
+    hash.to_bytes()[..8].try_into().unwrap()
+}
+
+/// Returns an account with a new unique key and the given flags. The account is leaked so it
+/// can be borrowed for any lifetime.
+pub(crate) fn account_info(is_signer: bool, is_writable: bool) -> AccountInfo<'static> {
+    leaked_account_info(is_signer, is_writable, Pubkey::default(), vec![])
+}
+
+/// Returns an initialized, writable SPL token account.
+pub(crate) fn token_account() -> Account<'static, TokenAccount> {
+    let mut data = vec![0u8; SplAccount::LEN];
+    SplAccount {
+        state: AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    let info = leaked_account_info(false, true, anchor_spl::token::ID, data);
+    Account::try_from(Box::leak(Box::new(info))).unwrap()
+}
+
+/// Returns an initialized, read-only SPL token mint.
+pub(crate) fn mint() -> Account<'static, Mint> {
+    let mut data = vec![0u8; SplMint::LEN];
+    SplMint {
+        is_initialized: true,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    let info = leaked_account_info(false, false, anchor_spl::token::ID, data);
+    Account::try_from(Box::leak(Box::new(info))).unwrap()
+}
+
+/// Builds account metas from `(pubkey, is_signer, is_writable)` tuples.
+pub(crate) fn metas(accounts: &[(Pubkey, bool, bool)]) -> Vec<AccountMeta> {
+    accounts
+        .iter()
+        .map(|&(pubkey, is_signer, is_writable)| AccountMeta {
+            pubkey,
+            is_signer,
+            is_writable,
+        })
+        .collect()
+}
+
+fn leaked_account_info(
+    is_signer: bool,
+    is_writable: bool,
+    owner: Pubkey,
+    data: Vec<u8>,
+) -> AccountInfo<'static> {
+    AccountInfo::new(
+        Box::leak(Box::new(Pubkey::new_unique())),
+        is_signer,
+        is_writable,
+        Box::leak(Box::new(1)),
+        Box::leak(data.into_boxed_slice()),
+        Box::leak(Box::new(owner)),
+        false,
+        0,
+    )
+}
@@ -0,0 +1,228 @@
+//! Derivation of the program derived addresses used by the Switchboard oracle program.
+//!
+//! Each `find_*` function searches for the canonical bump and returns `(Pubkey, bump)`. Each
+//! `create_*` function takes a known bump, which is far cheaper on-chain, and returns an error if
+//! the seeds and bump do not produce a valid address.
+
+use crate::prelude::*;
+use crate::{ATOKEN_PID, TOKEN_PID};
+
+fn create_program_address(
+    seeds: &[&[u8]],
+    bump: u8,
+    program_id: &Pubkey,
+) -> anchor_lang::Result<Pubkey> {
+    let bump = [bump];
+    let seeds = [seeds, &[&bump[..]]].concat();
+    Pubkey::create_program_address(&seeds, program_id)
+        .map_err(|_| error!(SwitchboardError::PdaDerivationError))
+}
+
+/// Returns the address and bump of the program's SbState account.
+pub fn find_state() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STATE_SEED], &SWITCHBOARD_PROGRAM_ID)
+}
+
+/// Returns the address of the program's SbState account for a known bump.
+pub fn create_state(bump: u8) -> anchor_lang::Result<Pubkey> {
+    create_program_address(&[STATE_SEED], bump, &SWITCHBOARD_PROGRAM_ID)
+}
+
+/// Returns the address and bump of the permission account granted by `granter` to `grantee`.
+///
+/// # Arguments
+///
+/// * `authority` - The authority of the granter, usually the queue authority
+/// * `granter` - The account granting the permission, usually an oracle queue
+/// * `grantee` - The account receiving the permission, e.g. an oracle, aggregator or VRF account
+pub fn find_permission(authority: &Pubkey, granter: &Pubkey, grantee: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PERMISSION_SEED,
+            authority.as_ref(),
+            granter.as_ref(),
+            grantee.as_ref(),
+        ],
+        &SWITCHBOARD_PROGRAM_ID,
+    )
+}
+
+/// Returns the address of a permission account for a known bump.
+pub fn create_permission(
+    authority: &Pubkey,
+    granter: &Pubkey,
+    grantee: &Pubkey,
+    bump: u8,
+) -> anchor_lang::Result<Pubkey> {
+    create_program_address(
+        &[
+            PERMISSION_SEED,
+            authority.as_ref(),
+            granter.as_ref(),
+            grantee.as_ref(),
+        ],
+        bump,
+        &SWITCHBOARD_PROGRAM_ID,
+    )
+}
+
+/// Returns the address and bump of the lease account funding `aggregator` on `queue`.
+pub fn find_lease(queue: &Pubkey, aggregator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LEASE_SEED, queue.as_ref(), aggregator.as_ref()],
+        &SWITCHBOARD_PROGRAM_ID,
+    )
+}
+
+/// Returns the address of a lease account for a known bump.
+pub fn create_lease(queue: &Pubkey, aggregator: &Pubkey, bump: u8) -> anchor_lang::Result<Pubkey> {
+    create_program_address(
+        &[LEASE_SEED, queue.as_ref(), aggregator.as_ref()],
+        bump,
+        &SWITCHBOARD_PROGRAM_ID,
+    )
+}
+
+/// Returns the address and bump of the oracle account on `queue` staking from `wallet`.
+///
+/// # Arguments
+///
+/// * `queue` - The oracle queue the oracle heartbeats on
+/// * `wallet` - The oracle's stake token account
+pub fn find_oracle(queue: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ORACLE_SEED, queue.as_ref(), wallet.as_ref()],
+        &SWITCHBOARD_PROGRAM_ID,
+    )
+}
+
+/// Returns the address of an oracle account for a known bump.
+pub fn create_oracle(queue: &Pubkey, wallet: &Pubkey, bump: u8) -> anchor_lang::Result<Pubkey> {
+    create_program_address(
+        &[ORACLE_SEED, queue.as_ref(), wallet.as_ref()],
+        bump,
+        &SWITCHBOARD_PROGRAM_ID,
+    )
+}
+
+/// Returns the address and bump of the sliding result account of `aggregator`.
+pub fn find_sliding_result(aggregator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SLIDING_RESULT_SEED, aggregator.as_ref()],
+        &SWITCHBOARD_PROGRAM_ID,
+    )
+}
+
+/// Returns the address of a sliding result account for a known bump.
+pub fn create_sliding_result(aggregator: &Pubkey, bump: u8) -> anchor_lang::Result<Pubkey> {
+    create_program_address(
+        &[SLIDING_RESULT_SEED, aggregator.as_ref()],
+        bump,
+        &SWITCHBOARD_PROGRAM_ID,
+    )
+}
+
+/// Returns the address and bump of the associated token account of `owner` for `mint`. This is
+/// the address returned by [`find_associated_token_address`](crate::find_associated_token_address).
+pub fn find_associated_token(owner: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), TOKEN_PID.as_ref(), mint.as_ref()],
+        &ATOKEN_PID,
+    )
+}
+
+/// Returns the address of an associated token account for a known bump.
+pub fn create_associated_token(
+    owner: &Pubkey,
+    mint: &Pubkey,
+    bump: u8,
+) -> anchor_lang::Result<Pubkey> {
+    create_program_address(
+        &[owner.as_ref(), TOKEN_PID.as_ref(), mint.as_ref()],
+        bump,
+        &ATOKEN_PID,
+    )
+}
+
+/// Returns the address and bump of a lease's escrow, the lease's associated token account for
+/// the queue's mint.
+///
+/// # Arguments
+///
+/// * `lease` - The lease account
+/// * `mint` - The queue's mint, see [`OracleQueueAccountData::get_mint`]
+pub fn find_lease_escrow(lease: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    find_associated_token(lease, mint)
+}
+
+/// Returns the address of a lease's escrow for a known bump.
+pub fn create_lease_escrow(lease: &Pubkey, mint: &Pubkey, bump: u8) -> anchor_lang::Result<Pubkey> {
+    create_associated_token(lease, mint, bump)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_associated_token_address;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_find_matches_create() {
+        let queue = Pubkey::new_unique();
+        let aggregator = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+
+        let (state, bump) = find_state();
+        assert_eq!(create_state(bump).unwrap(), state);
+
+        let (permission, bump) = find_permission(&authority, &queue, &aggregator);
+        assert_eq!(
+            create_permission(&authority, &queue, &aggregator, bump).unwrap(),
+            permission
+        );
+        assert_ne!(
+            find_permission(&authority, &aggregator, &queue).0,
+            permission
+        );
+
+        let (lease, bump) = find_lease(&queue, &aggregator);
+        assert_eq!(create_lease(&queue, &aggregator, bump).unwrap(), lease);
+
+        let (oracle, bump) = find_oracle(&queue, &wallet);
+        assert_eq!(create_oracle(&queue, &wallet, bump).unwrap(), oracle);
+
+        let (sliding_result, bump) = find_sliding_result(&aggregator);
+        assert_eq!(
+            create_sliding_result(&aggregator, bump).unwrap(),
+            sliding_result
+        );
+
+        let (escrow, bump) = find_lease_escrow(&lease, &TOKEN_PID);
+        assert_eq!(escrow, find_associated_token_address(&lease, &TOKEN_PID));
+        assert_eq!(
+            create_lease_escrow(&lease, &TOKEN_PID, bump).unwrap(),
+            escrow
+        );
+    }
+
+    #[test]
+    fn test_known_addresses() {
+        let (state, _) = find_state();
+        assert_eq!(
+            state,
+            Pubkey::from_str("CyZuD7RPDcrqCGbNvLCyqk6Py9cEZTKmNKujfPi3ynDd").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_create_with_invalid_bump() {
+        let aggregator = Pubkey::new_unique();
+        let (sliding_result, bump) = find_sliding_result(&aggregator);
+        // a different bump never derives the canonical address, and may land on the curve
+        match create_sliding_result(&aggregator, bump.wrapping_add(1)) {
+            Ok(address) => assert_ne!(address, sliding_result),
+            Err(err) => assert_eq!(err, SwitchboardError::PdaDerivationError.into()),
+        }
+    }
+}
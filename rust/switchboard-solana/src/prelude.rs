@@ -13,6 +13,7 @@ pub use rust_decimal;
 
 cfg_program! {
     pub use anchor_lang;
+    pub use anchor_spl;
     pub use anchor_lang::solana_program;
 
     pub use anchor_lang::prelude::*;
@@ -0,0 +1,200 @@
+//! Unbiased helpers for deriving random values from a 32 byte VRF result.
+//!
+//! A [`RandomnessStream`] extends a seed with a SHA-256 hash chain, so a single VRF result can
+//! produce any number of values. Integers in a range are drawn with rejection sampling instead of
+//! `% n`, which would favor the low end of the range. Everything is deterministic and allocation
+//! free, and hashing uses the `sol_sha256` syscall when running on-chain.
+
+use crate::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+const SUB_SEED_DOMAIN: &[u8] = b"SwitchboardVrfSubSeed";
+const BLOCK_DOMAIN: &[u8] = b"SwitchboardVrfBlock";
+
+/// Derives an independent seed from a VRF result for the given domain, e.g. `b"raffle-winner"`.
+/// Different domains produce unrelated seeds, so one round can drive several draws.
+pub fn derive_sub_seed(seed: &[u8; 32], domain: &[u8]) -> [u8; 32] {
+    hashv(&[SUB_SEED_DOMAIN, seed, domain]).to_bytes()
+}
+
+/// A deterministic stream of random values derived from a VRF result.
+///
+/// # Examples
+///
+/// ```ignore
+/// use switchboard_solana::{RandomnessStream, VrfLiteAccountData};
+///
+/// let result = VrfLiteAccountData::new(vrf_lite_account_info)?.get_result()?;
+/// let mut randomness = RandomnessStream::new(&result, b"dice");
+/// let roll = randomness.gen_range(1, 7)?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RandomnessStream {
+    seed: [u8; 32],
+    counter: u64,
+    block: [u8; 32],
+    offset: usize,
+}
+
+impl RandomnessStream {
+    /// Returns a stream seeded with the sub-seed of `seed` for `domain`.
+    pub fn new(seed: &[u8; 32], domain: &[u8]) -> Self {
+        let seed = derive_sub_seed(seed, domain);
+
+        RandomnessStream {
+            seed,
+            counter: 0,
+            block: hashv(&[BLOCK_DOMAIN, &seed, &0u64.to_le_bytes()]).to_bytes(),
+            offset: 0,
+        }
+    }
+
+    /// Returns the next 8 bytes of the stream as a u64, hashing the next block of the chain
+    /// once the current one is used up.
+    pub fn next_u64(&mut self) -> u64 {
+        if self.offset == self.block.len() {
+            self.counter = self.counter.wrapping_add(1);
+            self.block = hashv(&[BLOCK_DOMAIN, &self.seed, &self.counter.to_le_bytes()]).to_bytes();
+            self.offset = 0;
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.block[self.offset..self.offset + 8]);
+        self.offset += 8;
+
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Returns a uniformly distributed integer in `[low, high)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The inclusive lower bound.
+    /// * `high` - The exclusive upper bound. Must be greater than `low`.
+    pub fn gen_range(&mut self, low: u64, high: u64) -> anchor_lang::Result<u64> {
+        if high <= low {
+            return Err(error!(SwitchboardError::EmptyRandomnessRange));
+        }
+        let range = high - low;
+        // values above the zone fall in a partial final range and would bias the result
+        // towards the low end of the range
+        let zone = u64::MAX - (u64::MAX - range + 1) % range;
+        loop {
+            let value = self.next_u64();
+            if value <= zone {
+                return Ok(low + value % range);
+            }
+        }
+    }
+
+    /// Returns the index of an item picked with probability proportional to its weight.
+    /// Items with a weight of zero are never picked.
+    pub fn choose_weighted(&mut self, weights: &[u64]) -> anchor_lang::Result<usize> {
+        let total = weights
+            .iter()
+            .try_fold(0u64, |total, weight| total.checked_add(*weight))
+            .ok_or(SwitchboardError::InvalidRandomnessWeights)?;
+        if total == 0 {
+            return Err(error!(SwitchboardError::InvalidRandomnessWeights));
+        }
+
+        let mut target = self.gen_range(0, total)?;
+        weights
+            .iter()
+            .position(|weight| {
+                if target < *weight {
+                    return true;
+                }
+                target -= weight;
+                false
+            })
+            .ok_or_else(|| error!(SwitchboardError::InvalidRandomnessWeights))
+    }
+
+    /// Shuffles the items in place with a Fisher-Yates shuffle, so every permutation is
+    /// equally likely.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            // the range is never empty, i + 1 > 0
+            let j = self.gen_range(0, i as u64 + 1).unwrap_or_default() as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic_streams() {
+        let seed = [7u8; 32];
+        let mut a = RandomnessStream::new(&seed, b"a");
+        let mut b = RandomnessStream::new(&seed, b"a");
+        let values: Vec<u64> = (0..20).map(|_| a.next_u64()).collect();
+        assert_eq!(values, (0..20).map(|_| b.next_u64()).collect::<Vec<_>>());
+
+        // the chain keeps producing fresh values past the first block
+        assert_ne!(values[..4], values[4..8]);
+
+        let mut c = RandomnessStream::new(&seed, b"b");
+        assert_ne!(values[0], c.next_u64());
+        assert_ne!(derive_sub_seed(&seed, b"a"), derive_sub_seed(&seed, b"b"));
+    }
+
+    #[test]
+    fn test_gen_range() {
+        let mut randomness = RandomnessStream::new(&[1u8; 32], b"range");
+        let mut counts = [0u32; 6];
+        for _ in 0..6000 {
+            let roll = randomness.gen_range(1, 7).unwrap();
+            assert!((1..7).contains(&roll));
+            counts[roll as usize - 1] += 1;
+        }
+        assert!(counts.iter().all(|count| (800..1200).contains(count)));
+
+        assert_eq!(randomness.gen_range(5, 6).unwrap(), 5);
+        assert!(randomness.gen_range(0, u64::MAX).unwrap() < u64::MAX);
+        assert_eq!(
+            randomness.gen_range(6, 6).unwrap_err(),
+            SwitchboardError::EmptyRandomnessRange.into()
+        );
+    }
+
+    #[test]
+    fn test_choose_weighted() {
+        let mut randomness = RandomnessStream::new(&[2u8; 32], b"weighted");
+        let mut counts = [0u32; 3];
+        for _ in 0..4000 {
+            counts[randomness.choose_weighted(&[1, 0, 3]).unwrap()] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert!((800..1200).contains(&counts[0]));
+
+        assert_eq!(
+            randomness.choose_weighted(&[0, 0]).unwrap_err(),
+            SwitchboardError::InvalidRandomnessWeights.into()
+        );
+        assert_eq!(
+            randomness.choose_weighted(&[]).unwrap_err(),
+            SwitchboardError::InvalidRandomnessWeights.into()
+        );
+        assert_eq!(
+            randomness.choose_weighted(&[u64::MAX, 1]).unwrap_err(),
+            SwitchboardError::InvalidRandomnessWeights.into()
+        );
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut randomness = RandomnessStream::new(&[3u8; 32], b"shuffle");
+        let mut items: Vec<u32> = (0..100).collect();
+        randomness.shuffle(&mut items);
+        assert_ne!(items, (0..100).collect::<Vec<_>>());
+        let mut sorted = items.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..100).collect::<Vec<_>>());
+
+        let mut empty: [u32; 0] = [];
+        randomness.shuffle(&mut empty);
+    }
+}
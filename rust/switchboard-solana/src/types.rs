@@ -1,7 +1,12 @@
 pub use crate::decimal::SwitchboardDecimal;
 
 pub use crate::oracle_program::{
-    AggregatorHistoryRow, AggregatorResolutionMode, AggregatorRound, BufferRelayerRound, CrankRow,
-    OracleMetrics, OracleResponseType, PermissionSetParams, SlidingWindowElement,
-    SwitchboardPermission,
+    AccountMetaBorsh, AccountMetaZC, AggregatorHistoryRow, AggregatorResolutionMode,
+    AggregatorRound, BufferRelayerRound, Callback, CallbackZC, CompletedPoint, CompletedPointZC,
+    ConfidenceBound, CrankRow, EcvrfIntermediate, EcvrfProofZC, EdwardsPoint, EdwardsPointZC,
+    FeedCheck, FeedPolicy, FeedPolicyReport, FieldElement51, FieldElementZC, LeaseRunway,
+    OracleHealth, OracleHealthGrade, OracleMetrics, OracleResponseType, PermissionSetParams,
+    ProjectivePoint, ProjectivePointZC, Scalar, SlidingWindowElement, StalenessBound,
+    SwitchboardPermission, VrfBuilder, VrfBuilderReport, VrfPoolRow, VrfPoolView, VrfRound,
+    VrfStatus,
 };
@@ -11,10 +11,7 @@ lazy_static! {
 }
 
 pub fn find_associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
-    let (akey, _bump) = Pubkey::find_program_address(
-        &[owner.as_ref(), TOKEN_PID.as_ref(), mint.as_ref()],
-        &ATOKEN_PID,
-    );
+    let (akey, _bump) = crate::pda::find_associated_token(owner, mint);
     akey
 }
 